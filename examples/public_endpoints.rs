@@ -1,6 +1,6 @@
 use kraken_api::{
     api::{
-        futures::public::analytics::{Analytics, AnalyticsResp},
+        futures::public::analytics::{Analytics, OpenInterestData, OpenInterestResp},
         query::AsyncQuery,
         spot::public::{
             orderbook::{OrderBook, OrderBookResp},
@@ -19,12 +19,13 @@ async fn main() {
     let r: TimeResp = endpoint.query_async(&client).await.unwrap();
     println!("{r:#?}");
 
-    let endpoint = Analytics::builder()
+    let endpoint = Analytics::builder_for::<OpenInterestData>()
+        .symbol("PF_XBTUSD")
         .interval(604800)
         .since(1676556478)
         .build()
         .unwrap();
-    let r: AnalyticsResp = endpoint.query_async(&client).await.unwrap();
+    let r: OpenInterestResp = endpoint.query_async(&client).await.unwrap();
     println!("{r:#?}");
 
     let endpoint = OrderBook::builder().pair("XXBTZUSD").build().unwrap();
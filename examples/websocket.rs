@@ -0,0 +1,36 @@
+use kraken_api::{
+    api::common::Symbols,
+    ws::{
+        client::KrakenWs,
+        subscription::{Channel, OhlcInterval, Subscription},
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    let (client, mut events) = KrakenWs::connect().await.unwrap();
+
+    let subscription = Subscription::builder()
+        .channel(Channel::Ticker)
+        .symbols(Symbols::Only(vec!["BTC/USD"]))
+        .build()
+        .unwrap();
+    let r = client.subscribe(&subscription).await.unwrap();
+    println!("{r:#?}");
+
+    let subscription = Subscription::builder()
+        .channel(Channel::Ohlc)
+        .symbols(Symbols::Only(vec!["BTC/USD"]))
+        .interval(OhlcInterval::OneMin)
+        .build()
+        .unwrap();
+    let r = client.subscribe(&subscription).await.unwrap();
+    println!("{r:#?}");
+
+    for _ in 0..10 {
+        let message = events.recv().await.unwrap().unwrap();
+        println!("{message:#?}");
+    }
+
+    client.close().await.unwrap();
+}
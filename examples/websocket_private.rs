@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use kraken_api::{
+    api::spot::authenticated::{
+        add_order::{AddOrder, OrderSide, OrderType},
+        websockets::token_manager::WebSocketsTokenManager,
+    },
+    kraken::AsyncKraken,
+    ws::{
+        client::KrakenWs,
+        subscription::{Channel, Subscription},
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    let spot_client = Arc::new(AsyncKraken::new_auth("YOUR_API_KEY", "YOUR_SECRET_KEY"));
+    let tokens = WebSocketsTokenManager::new(spot_client);
+
+    let token = tokens.token().await.unwrap();
+    let (client, mut events) = KrakenWs::connect_private(token).await.unwrap();
+
+    let subscription = Subscription::builder()
+        .channel(Channel::Executions)
+        .snap_orders(true)
+        .snap_trades(false)
+        .build()
+        .unwrap();
+    let r = client.subscribe(&subscription).await.unwrap();
+    println!("{r:#?}");
+
+    let subscription = Subscription::builder()
+        .channel(Channel::Balances)
+        .build()
+        .unwrap();
+    let r = client.subscribe(&subscription).await.unwrap();
+    println!("{r:#?}");
+
+    let order = AddOrder::builder()
+        .order_type(OrderType::Limit)
+        .side(OrderSide::Buy)
+        .volume(0.0001)
+        .price(20000.0)
+        .pair("BTC/USD")
+        .validate_args(true)
+        .build()
+        .unwrap();
+    let r = client.add_order(&order).await.unwrap();
+    println!("{r:#?}");
+
+    for _ in 0..10 {
+        let message = events.recv().await.unwrap().unwrap();
+        println!("{message:#?}");
+    }
+
+    client.close().await.unwrap();
+}
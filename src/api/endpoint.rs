@@ -36,7 +36,7 @@ pub trait Endpoint {
     }
 
     /// Query parameters for the endpoint.
-    fn parameters(&self) -> Option<QueryParams> {
+    fn parameters(&self) -> Option<QueryParams<'_>> {
         None
     }
 
@@ -106,13 +106,11 @@ where
         }
 
         // Deserialize into whatever type the caller is asking.
-        serde_json::from_value::<T>(v.clone())
-            .map(|response| response)
-            .map_err(|e| ApiError::DataType {
-                typename: any::type_name::<T>(),
-                obj: v,
-                source: e,
-            })
+        serde_json::from_value::<T>(v.clone()).map_err(|e| ApiError::DataType {
+            typename: any::type_name::<T>(),
+            obj: v,
+            source: e,
+        })
     }
 }
 
@@ -176,14 +174,11 @@ where
             });
         }
 
-
         // Deserialize into whatever type the caller is asking.
-        serde_json::from_value::<T>(v.clone())
-            .map(|response| response)
-            .map_err(|e| ApiError::DataType {
-                typename: any::type_name::<T>(),
-                obj: v,
-                source: e,
-            })
+        serde_json::from_value::<T>(v.clone()).map_err(|e| ApiError::DataType {
+            typename: any::type_name::<T>(),
+            obj: v,
+            source: e,
+        })
     }
 }
@@ -1,7 +1,6 @@
 use derive_builder::Builder;
 use http::Method;
-use serde::{Deserialize, Deserializer, Serialize};
-use serde_json::{Map, Value};
+use serde::{Deserialize, Serialize};
 
 use crate::api::{
     endpoint::{Endpoint, EndpointType},
@@ -81,7 +80,7 @@ impl Endpoint for AccountLog {
         EndpointType::Futures
     }
 
-    fn parameters(&self) -> Option<QueryParams> {
+    fn parameters(&self) -> Option<QueryParams<'_>> {
         let mut params = QueryParams::default();
 
         if let Some(before) = &self.before {
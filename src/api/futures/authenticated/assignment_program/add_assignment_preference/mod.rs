@@ -0,0 +1,75 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+pub use super::get_assignment_programs::{AssignmentPreference, AssignmentTimeFrame};
+
+/// Joins the assignment program for a contract type, or a single contract if `contract` is set.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+#[serde(rename_all = "camelCase")]
+pub struct AddAssignmentPreference {
+    pub contract_type: String,
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<f64>,
+    #[builder(setter(strip_option), default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_position: Option<f64>,
+    pub accept_long: bool,
+    pub accept_short: bool,
+    #[builder(default)]
+    pub time_frame: AssignmentTimeFrame,
+    #[builder(default = "true")]
+    pub enabled: bool,
+}
+
+impl AddAssignmentPreference {
+    pub fn builder() -> AddAssignmentPreferenceBuilder {
+        AddAssignmentPreferenceBuilder::default()
+    }
+}
+
+impl Endpoint for AddAssignmentPreference {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/assignmentprogram/add")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAssignmentPreferenceResp {
+    pub participant: Option<AssignmentPreference>,
+    pub result: String,
+    pub server_time: String,
+}
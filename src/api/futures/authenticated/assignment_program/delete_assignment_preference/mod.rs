@@ -0,0 +1,57 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+pub use super::get_assignment_programs::AssignmentPreference;
+
+#[derive(Debug, Clone, Builder, Serialize)]
+pub struct DeleteAssignmentPreference {
+    pub id: u64,
+}
+
+impl DeleteAssignmentPreference {
+    pub fn builder() -> DeleteAssignmentPreferenceBuilder {
+        DeleteAssignmentPreferenceBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteAssignmentPreference {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/assignmentprogram/delete")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteAssignmentPreferenceResp {
+    pub participant: Option<AssignmentPreference>,
+    pub result: String,
+    pub server_time: String,
+}
@@ -0,0 +1,52 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+pub use super::get_assignment_programs::AssignmentPreference;
+
+/// Changes made to the assignment program preferences of the account.
+#[derive(Debug, Clone, Builder)]
+pub struct GetAssignmentHistory {}
+
+impl GetAssignmentHistory {
+    pub fn builder() -> GetAssignmentHistoryBuilder {
+        GetAssignmentHistoryBuilder::default()
+    }
+}
+
+impl Endpoint for GetAssignmentHistory {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/assignmentprogram/history")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignmentHistoryEntry {
+    pub participant: AssignmentPreference,
+    pub deleted: bool,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAssignmentHistoryResp {
+    #[serde(default)]
+    pub participants: Vec<AssignmentHistoryEntry>,
+    pub result: String,
+    pub server_time: String,
+}
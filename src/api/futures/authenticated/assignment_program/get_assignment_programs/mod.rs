@@ -0,0 +1,68 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::deserialize_option_number_from_string;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AssignmentTimeFrame {
+    #[default]
+    All,
+    Weekdays,
+    Weekends,
+}
+
+/// Current assignment program preferences of the account.
+#[derive(Debug, Clone, Builder)]
+pub struct GetAssignmentPrograms {}
+
+impl GetAssignmentPrograms {
+    pub fn builder() -> GetAssignmentProgramsBuilder {
+        GetAssignmentProgramsBuilder::default()
+    }
+}
+
+impl Endpoint for GetAssignmentPrograms {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/assignmentprogram/current")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignmentPreference {
+    pub id: u64,
+    pub contract_type: String,
+    pub contract: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub max_size: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub max_position: Option<f64>,
+    pub accept_long: bool,
+    pub accept_short: bool,
+    pub time_frame: AssignmentTimeFrame,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAssignmentProgramsResp {
+    #[serde(default)]
+    pub participants: Vec<AssignmentPreference>,
+    pub result: String,
+    pub server_time: String,
+}
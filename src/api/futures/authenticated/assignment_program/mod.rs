@@ -0,0 +1,4 @@
+pub mod get_assignment_programs;
+pub mod add_assignment_preference;
+pub mod delete_assignment_preference;
+pub mod get_assignment_history;
\ No newline at end of file
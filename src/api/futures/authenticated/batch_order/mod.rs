@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+use super::{
+    cancel_order::CancelOrder,
+    edit_order::EditOrder,
+    send_order::{OrderEvent, SendOrder, SendOrderStatus},
+};
+
+/// A single instruction of a batch, reusing the fields of the standalone endpoints.
+#[derive(Debug, Clone)]
+pub enum BatchInstruction {
+    /// `order_tag` is an arbitrary identifier used to match the instruction with its status.
+    Send { order_tag: String, order: SendOrder },
+    Edit(EditOrder),
+    Cancel(CancelOrder),
+}
+
+impl BatchInstruction {
+    pub fn send(order_tag: impl Into<String>, order: SendOrder) -> Self {
+        BatchInstruction::Send {
+            order_tag: order_tag.into(),
+            order,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        let (kind, serialized_params) = match self {
+            BatchInstruction::Send { order, .. } => ("send", serde_json::to_value(order)),
+            BatchInstruction::Edit(order) => ("edit", serde_json::to_value(order)),
+            BatchInstruction::Cancel(order) => ("cancel", serde_json::to_value(order)),
+        };
+
+        let mut params = match serialized_params.expect("Serialization failed") {
+            Value::Object(params) => params,
+            _ => Map::new(),
+        };
+
+        // The batch endpoint identifies orders with `order_id`, the standalone ones do not agree.
+        if let Some(order_id) = params.remove("orderId") {
+            params.insert("order_id".to_string(), order_id);
+        }
+
+        // Only relevant to the standalone endpoints, the batch has its own.
+        params.remove("processBefore");
+
+        params.insert("order".to_string(), Value::String(kind.to_string()));
+
+        if let BatchInstruction::Send { order_tag, .. } = self {
+            params.insert("order_tag".to_string(), Value::String(order_tag.clone()));
+        }
+
+        Value::Object(params)
+    }
+}
+
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(build_fn(validate = "Self::validate"), setter(strip_option, into), default)]
+pub struct BatchOrder {
+    pub instructions: Vec<BatchInstruction>,
+    pub process_before: Option<String>,
+}
+
+impl BatchOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let instructions = self.instructions.as_deref().unwrap_or_default();
+
+        if instructions.is_empty() {
+            return Err("A batch must contain at least one instruction".to_string());
+        }
+
+        let mut order_tags = HashSet::new();
+        for instruction in instructions {
+            if let BatchInstruction::Send { order_tag, .. } = instruction {
+                if !order_tags.insert(order_tag) {
+                    return Err(format!("Duplicated order_tag {order_tag}"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BatchOrder {
+    pub fn builder() -> BatchOrderBuilder {
+        BatchOrderBuilder::default()
+    }
+}
+
+impl Endpoint for BatchOrder {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/batchorder")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let mut batch = Map::new();
+        batch.insert(
+            "batchOrder".to_string(),
+            Value::Array(
+                self.instructions
+                    .iter()
+                    .map(BatchInstruction::to_value)
+                    .collect(),
+            ),
+        );
+
+        let mut params = Map::new();
+
+        // The nested instructions are sent as a JSON document in the `json` form field.
+        params.insert(
+            "json".to_string(),
+            Value::String(Value::Object(batch).to_string()),
+        );
+
+        if let Some(process_before) = &self.process_before {
+            params.insert(
+                "processBefore".to_string(),
+                Value::String(process_before.clone()),
+            );
+        }
+
+        Some(("application/x-www-form-urlencoded", params))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchStatus {
+    pub status: SendOrderStatus,
+    // Kraken uses snake case for these ones
+    #[serde(rename = "order_tag")]
+    pub order_tag: Option<String>,
+    #[serde(rename = "order_id")]
+    pub order_id: Option<String>,
+    pub cli_ord_id: Option<String>,
+    pub date_time_received: Option<String>,
+    #[serde(default)]
+    pub order_events: Vec<OrderEvent>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrderResp {
+    pub result: String,
+    #[serde(default)]
+    pub batch_status: Vec<BatchStatus>,
+    pub server_time: String,
+}
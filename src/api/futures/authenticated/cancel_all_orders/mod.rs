@@ -0,0 +1,84 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+/// Cancels all open orders, or only the ones of `symbol` if provided.
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct CancelAllOrders {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl CancelAllOrders {
+    pub fn builder() -> CancelAllOrdersBuilder {
+        CancelAllOrdersBuilder::default()
+    }
+}
+
+impl Endpoint for CancelAllOrders {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/cancelallorders")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CancelAllOrdersStatus {
+    NoOrdersToCancel,
+    Cancelled,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelledOrder {
+    // Kraken made a typo apparently
+    #[serde(rename = "order_id")]
+    pub order_id: String,
+    pub cli_ord_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllStatus {
+    pub received_time: String,
+    pub cancel_only: String,
+    pub status: CancelAllOrdersStatus,
+    #[serde(default)]
+    pub cancelled_orders: Vec<CancelledOrder>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOrdersResp {
+    pub result: String,
+    pub cancel_status: CancelAllStatus,
+    pub server_time: String,
+}
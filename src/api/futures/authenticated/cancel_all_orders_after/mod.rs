@@ -0,0 +1,66 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+/// Dead man's switch: cancels all orders once `timeout` seconds have elapsed.
+///
+/// The timer has to be refreshed periodically, sending a `timeout` of 0 disables it.
+#[derive(Debug, Clone, Copy, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct CancelAllOrdersAfter {
+    pub timeout: u64,
+}
+
+impl CancelAllOrdersAfter {
+    pub fn builder() -> CancelAllOrdersAfterBuilder {
+        CancelAllOrdersAfterBuilder::default()
+    }
+}
+
+impl Endpoint for CancelAllOrdersAfter {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/cancelallordersafter")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadManSwitchStatus {
+    pub current_time: String,
+    pub trigger_time: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOrdersAfterResp {
+    pub result: String,
+    pub status: DeadManSwitchStatus,
+    pub server_time: String,
+}
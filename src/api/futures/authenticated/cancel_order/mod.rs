@@ -0,0 +1,91 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+use super::send_order::{OrderEvent, SendOrderStatus};
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(build_fn(validate = "Self::validate"), setter(strip_option, into), default)]
+#[serde(rename_all(serialize = "camelCase"))]
+pub struct CancelOrder {
+    // Kraken expects snake case for this one
+    #[serde(rename = "order_id", skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cli_ord_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_before: Option<String>,
+}
+
+impl CancelOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match (
+            self.order_id.clone().flatten(),
+            self.cli_ord_id.clone().flatten(),
+        ) {
+            (None, None) => Err("Either order_id or cli_ord_id must be set".to_string()),
+            (Some(_), Some(_)) => Err("Only one of order_id and cli_ord_id can be set".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl CancelOrder {
+    pub fn builder() -> CancelOrderBuilder {
+        CancelOrderBuilder::default()
+    }
+}
+
+impl Endpoint for CancelOrder {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/cancelorder")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelStatus {
+    // Kraken made a typo apparently
+    #[serde(rename = "order_id")]
+    pub order_id: Option<String>,
+    pub cli_ord_id: Option<String>,
+    pub received_time: String,
+    pub status: SendOrderStatus,
+    #[serde(default)]
+    pub order_events: Vec<OrderEvent>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderResp {
+    pub result: String,
+    pub cancel_status: CancelStatus,
+    pub server_time: String,
+}
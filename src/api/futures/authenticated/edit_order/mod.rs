@@ -0,0 +1,110 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+use super::send_order::{LimitPriceOffsetUnit, OrderEvent, SendOrderStatus};
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(build_fn(validate = "Self::validate"), setter(strip_option, into), default)]
+#[serde(rename_all(serialize = "camelCase"))]
+pub struct EditOrder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cli_ord_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_deviation_unit: Option<LimitPriceOffsetUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop_max_deviation: Option<f64>,
+}
+
+impl EditOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match (
+            self.order_id.clone().flatten(),
+            self.cli_ord_id.clone().flatten(),
+        ) {
+            (None, None) => return Err("Either order_id or cli_ord_id must be set".to_string()),
+            (Some(_), Some(_)) => {
+                return Err("Only one of order_id and cli_ord_id can be set".to_string())
+            }
+            _ => {}
+        }
+
+        if self.size.flatten().is_none()
+            && self.limit_price.flatten().is_none()
+            && self.stop_price.flatten().is_none()
+            && self.trailing_stop_max_deviation.flatten().is_none()
+        {
+            return Err("At least one field to edit must be set".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl EditOrder {
+    pub fn builder() -> EditOrderBuilder {
+        EditOrderBuilder::default()
+    }
+}
+
+impl Endpoint for EditOrder {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/editorder")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EditStatus {
+    pub order_id: Option<String>,
+    pub cli_ord_id: Option<String>,
+    pub received_time: Option<String>,
+    pub status: SendOrderStatus,
+    #[serde(default)]
+    pub order_events: Vec<OrderEvent>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EditOrderResp {
+    pub result: String,
+    pub edit_status: EditStatus,
+    pub server_time: String,
+}
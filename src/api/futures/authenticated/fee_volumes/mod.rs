@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct FeeVolumes {}
+
+impl FeeVolumes {
+    pub fn builder() -> FeeVolumesBuilder {
+        FeeVolumesBuilder::default()
+    }
+}
+
+impl Endpoint for FeeVolumes {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/feeschedules/volumes")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeVolumesResp {
+    pub result: String,
+    /// The 30 day USD volume of the account, by fee schedule uid.
+    #[serde(default)]
+    pub volumes_by_fee_schedule: HashMap<String, f64>,
+    pub server_time: String,
+}
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::{
+    client::{AsyncClient, Client},
+    endpoint::{Endpoint, EndpointType},
+    error::ApiError,
+    params::QueryParams,
+    query::{AsyncQuery, Query},
+};
+
+use super::send_order::OrderSide;
+
+/// Returns the last 100 fills, or the 100 fills preceding `last_fill_time` if provided.
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct Fills {
+    pub last_fill_time: Option<String>,
+}
+
+impl Fills {
+    pub fn builder() -> FillsBuilder {
+        FillsBuilder::default()
+    }
+}
+
+impl Endpoint for Fills {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/fills")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        params.push_opt("lastFillTime", self.last_fill_time.as_ref());
+
+        Some(params)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FillType {
+    Maker,
+    Taker,
+    Liquidation,
+    Assignor,
+    Assignee,
+    TakerAfterEdit,
+    UnwindBankrupt,
+    UnwindCounterparty,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Fill {
+    // Kraken made a typo apparently
+    #[serde(rename = "fill_id")]
+    pub fill_id: String,
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(rename = "order_id")]
+    pub order_id: String,
+    pub cli_ord_id: Option<String>,
+    pub size: f64,
+    pub price: f64,
+    pub fill_time: String,
+    pub fill_type: FillType,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FillsResp {
+    pub result: String,
+    #[serde(default)]
+    pub fills: Vec<Fill>,
+    pub server_time: String,
+}
+
+/// Appends the fills not seen yet and returns the time to query the next (older) page from.
+fn merge_page(
+    fills: &mut Vec<Fill>,
+    seen: &mut HashSet<String>,
+    page: Vec<Fill>,
+) -> Option<String> {
+    let oldest = page.iter().map(|fill| &fill.fill_time).min().cloned();
+    let before = fills.len();
+
+    fills.extend(
+        page.into_iter()
+            .filter(|fill| seen.insert(fill.fill_id.clone())),
+    );
+
+    // Stop when a page does not bring anything new.
+    (fills.len() > before).then_some(oldest).flatten()
+}
+
+/// Pages backwards through the fills, starting before `last_fill_time` (or now), until at least
+/// `limit` fills have been collected or there are no older fills.
+pub fn fills_history<C>(
+    client: &C,
+    last_fill_time: Option<String>,
+    limit: usize,
+) -> Result<Vec<Fill>, ApiError<C::Error>>
+where
+    C: for<'a> Client<'a>,
+{
+    let mut fills = Vec::new();
+    let mut seen = HashSet::new();
+    let mut endpoint = Fills { last_fill_time };
+
+    while fills.len() < limit {
+        let rsp: FillsResp = endpoint.query(client)?;
+
+        match merge_page(&mut fills, &mut seen, rsp.fills) {
+            Some(oldest) => endpoint.last_fill_time = Some(oldest),
+            None => break,
+        }
+    }
+
+    Ok(fills)
+}
+
+/// Pages backwards through the fills asynchronously, see `fills_history`.
+pub async fn fills_history_async<C>(
+    client: &C,
+    last_fill_time: Option<String>,
+    limit: usize,
+) -> Result<Vec<Fill>, ApiError<C::Error>>
+where
+    C: for<'a> AsyncClient<'a> + Sync,
+{
+    let mut fills = Vec::new();
+    let mut seen = HashSet::new();
+    let mut endpoint = Fills { last_fill_time };
+
+    while fills.len() < limit {
+        let rsp: FillsResp = endpoint.query_async(client).await?;
+
+        match merge_page(&mut fills, &mut seen, rsp.fills) {
+            Some(oldest) => endpoint.last_fill_time = Some(oldest),
+            None => break,
+        }
+    }
+
+    Ok(fills)
+}
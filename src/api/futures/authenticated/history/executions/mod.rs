@@ -0,0 +1,61 @@
+use derive_builder::Builder;
+use http::Method;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    futures::history_common::{push_history_params, ExecutionEvent, HistoryResp, HistorySort},
+    params::QueryParams,
+};
+
+/// Executions of the account, pass the `continuation_token` of a page to get the next one.
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct ExecutionHistory {
+    pub since: Option<i64>,
+    pub before: Option<i64>,
+    pub sort: Option<HistorySort>,
+    pub continuation_token: Option<String>,
+    pub count: Option<u64>,
+    pub tradeable: Option<String>,
+}
+
+impl ExecutionHistory {
+    pub fn builder() -> ExecutionHistoryBuilder {
+        ExecutionHistoryBuilder::default()
+    }
+}
+
+impl Endpoint for ExecutionHistory {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/api/history/v2/executions")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        push_history_params(
+            &mut params,
+            self.since,
+            self.before,
+            self.sort,
+            self.continuation_token.as_deref(),
+            self.count,
+        );
+        params.push_opt("tradeable", self.tradeable.as_ref());
+
+        Some(params)
+    }
+}
+
+pub type ExecutionHistoryResp = HistoryResp<ExecutionEvent>;
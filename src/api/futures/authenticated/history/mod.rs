@@ -0,0 +1,3 @@
+pub mod orders;
+pub mod triggers;
+pub mod executions;
\ No newline at end of file
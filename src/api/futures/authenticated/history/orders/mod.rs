@@ -0,0 +1,169 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_aux::prelude::deserialize_option_number_from_string;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    futures::{
+        authenticated::send_order::{
+            CancelEvent, EditEvent, EventType, OrderEvent, PlaceEvent, RejectEvent,
+        },
+        history_common::{push_history_params, HistoryOrder, HistoryResp, HistorySort},
+    },
+    params::QueryParams,
+};
+
+/// Order events of the account, pass the `continuation_token` of a page to get the next one.
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct OrderHistory {
+    pub since: Option<i64>,
+    pub before: Option<i64>,
+    pub sort: Option<HistorySort>,
+    pub continuation_token: Option<String>,
+    pub count: Option<u64>,
+    pub tradeable: Option<String>,
+}
+
+impl OrderHistory {
+    pub fn builder() -> OrderHistoryBuilder {
+        OrderHistoryBuilder::default()
+    }
+}
+
+impl Endpoint for OrderHistory {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/api/history/v2/orders")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        push_history_params(
+            &mut params,
+            self.since,
+            self.before,
+            self.sort,
+            self.continuation_token.as_deref(),
+            self.count,
+        );
+        params.push_opt("tradeable", self.tradeable.as_ref());
+
+        Some(params)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderPlaced {
+    pub order: HistoryOrder,
+    pub reason: String,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub reduced_quantity: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderUpdated {
+    pub old_order: HistoryOrder,
+    pub new_order: HistoryOrder,
+    pub reason: String,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub reduced_quantity: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCancelled {
+    pub order: HistoryOrder,
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRejected {
+    pub order: HistoryOrder,
+    pub reason: String,
+    pub order_error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderEditRejected {
+    pub attempted_order: HistoryOrder,
+    pub old_order: HistoryOrder,
+    pub reason: String,
+    pub order_error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderNotFound {
+    pub order_id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub enum OrderHistoryEvent {
+    OrderPlaced(OrderPlaced),
+    OrderUpdated(OrderUpdated),
+    OrderCancelled(OrderCancelled),
+    OrderRejected(OrderRejected),
+    OrderEditRejected(OrderEditRejected),
+    OrderNotFound(OrderNotFound),
+}
+
+pub type OrderHistoryResp = HistoryResp<OrderHistoryEvent>;
+
+impl OrderHistoryEvent {
+    /// Converts the event to the `sendorder` event model, `None` for `OrderNotFound`.
+    pub fn into_order_event(self) -> Option<OrderEvent> {
+        let event = match self {
+            OrderHistoryEvent::OrderPlaced(placed) => OrderEvent::PlaceEvent(PlaceEvent {
+                order: placed.order.into(),
+                reduced_quantity: placed.reduced_quantity,
+                r#type: EventType::Place,
+            }),
+            OrderHistoryEvent::OrderUpdated(updated) => OrderEvent::EditEvent(EditEvent {
+                new: updated.new_order.into(),
+                old: updated.old_order.into(),
+                reduced_quantity: updated.reduced_quantity,
+                r#type: EventType::Edit,
+            }),
+            OrderHistoryEvent::OrderCancelled(cancelled) => OrderEvent::CancelEvent(CancelEvent {
+                uid: cancelled.order.uid.clone(),
+                order: cancelled.order.into(),
+                r#type: EventType::Cancel,
+            }),
+            OrderHistoryEvent::OrderRejected(rejected) => OrderEvent::RejectEvent(RejectEvent {
+                uid: rejected.order.uid.clone(),
+                order: rejected.order.into(),
+                reason: rejected.reason,
+                r#type: EventType::Reject,
+            }),
+            OrderHistoryEvent::OrderEditRejected(rejected) => {
+                OrderEvent::RejectEvent(RejectEvent {
+                    uid: rejected.attempted_order.uid.clone(),
+                    order: rejected.attempted_order.into(),
+                    reason: rejected.reason,
+                    r#type: EventType::Reject,
+                })
+            }
+            OrderHistoryEvent::OrderNotFound(_) => return None,
+        };
+
+        Some(event)
+    }
+}
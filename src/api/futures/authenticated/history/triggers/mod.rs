@@ -0,0 +1,114 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    futures::{
+        authenticated::send_order::{
+            CancelTriggerEvent, EventType, OrderEvent, PlaceTriggerEvent, RejectTriggerEvent,
+        },
+        history_common::{push_history_params, HistoryOrderTrigger, HistoryResp, HistorySort},
+    },
+    params::QueryParams,
+};
+
+/// Trigger order events of the account, pass the `continuation_token` of a page to get the next one.
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct TriggerHistory {
+    pub since: Option<i64>,
+    pub before: Option<i64>,
+    pub sort: Option<HistorySort>,
+    pub continuation_token: Option<String>,
+    pub count: Option<u64>,
+    pub tradeable: Option<String>,
+}
+
+impl TriggerHistory {
+    pub fn builder() -> TriggerHistoryBuilder {
+        TriggerHistoryBuilder::default()
+    }
+}
+
+impl Endpoint for TriggerHistory {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/api/history/v2/triggers")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        push_history_params(
+            &mut params,
+            self.since,
+            self.before,
+            self.sort,
+            self.continuation_token.as_deref(),
+            self.count,
+        );
+        params.push_opt("tradeable", self.tradeable.as_ref());
+
+        Some(params)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerEvent {
+    pub order: HistoryOrderTrigger,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub enum TriggerHistoryEvent {
+    OrderTriggerPlaced(TriggerEvent),
+    OrderTriggerCancelled(TriggerEvent),
+    OrderTriggerActivated(TriggerEvent),
+    OrderTriggerRejected(TriggerEvent),
+}
+
+pub type TriggerHistoryResp = HistoryResp<TriggerHistoryEvent>;
+
+impl TriggerHistoryEvent {
+    /// Converts the event to the `sendorder` event model, `None` for `OrderTriggerActivated`.
+    pub fn into_order_event(self) -> Option<OrderEvent> {
+        let event = match self {
+            TriggerHistoryEvent::OrderTriggerPlaced(placed) => {
+                OrderEvent::PlaceTriggerEvent(PlaceTriggerEvent {
+                    order_trigger: placed.order.into(),
+                    r#type: EventType::Place,
+                })
+            }
+            TriggerHistoryEvent::OrderTriggerCancelled(cancelled) => {
+                OrderEvent::CancelTriggerEvent(CancelTriggerEvent {
+                    uid: cancelled.order.uid.clone(),
+                    order_trigger: cancelled.order.into(),
+                    r#type: EventType::Cancel,
+                })
+            }
+            TriggerHistoryEvent::OrderTriggerRejected(rejected) => {
+                OrderEvent::RejectTriggerEvent(RejectTriggerEvent {
+                    uid: rejected.order.uid.clone(),
+                    order_trigger: rejected.order.into(),
+                    reason: rejected.reason.unwrap_or_default(),
+                    r#type: EventType::Reject,
+                })
+            }
+            TriggerHistoryEvent::OrderTriggerActivated(_) => return None,
+        };
+
+        Some(event)
+    }
+}
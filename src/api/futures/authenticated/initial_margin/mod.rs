@@ -0,0 +1,108 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_aux::prelude::deserialize_option_number_from_string;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    params::{query_value, QueryParams},
+};
+
+use super::send_order::{OrderSide, OrderType, SendOrder};
+
+/// Estimates the initial margin an order would require.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"), setter(into))]
+pub struct InitialMargin {
+    pub order_type: OrderType,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub size: f64,
+    #[builder(setter(strip_option), default)]
+    pub limit_price: Option<f64>,
+}
+
+impl InitialMarginBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.symbol.as_ref().is_some_and(|symbol| symbol.is_empty()) {
+            return Err("symbol cannot be empty".to_string());
+        }
+
+        if self.size.is_some_and(|size| size <= 0.0) {
+            return Err("size must be positive".to_string());
+        }
+
+        let needs_limit_price = matches!(
+            self.order_type,
+            Some(OrderType::Limit | OrderType::Post | OrderType::Ioc)
+        );
+
+        if needs_limit_price && self.limit_price.flatten().is_none() {
+            return Err("limit_price is required with Limit, Post or Ioc order type".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl InitialMargin {
+    pub fn builder() -> InitialMarginBuilder {
+        InitialMarginBuilder::default()
+    }
+}
+
+impl From<&SendOrder> for InitialMargin {
+    fn from(order: &SendOrder) -> Self {
+        Self {
+            order_type: order.order_type.clone(),
+            symbol: order.symbol.clone(),
+            side: order.side.clone(),
+            size: order.size,
+            limit_price: order.limit_price,
+        }
+    }
+}
+
+impl Endpoint for InitialMargin {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/initialmargin")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        params
+            .push("orderType", query_value(&self.order_type))
+            .push("symbol", &self.symbol)
+            .push("side", query_value(&self.side))
+            .push("size", self.size)
+            .push_opt("limitPrice", self.limit_price);
+
+        Some(params)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InitialMarginResp {
+    pub result: String,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub initial_margin: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub estimated_liquidation_threshold: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub price: Option<f64>,
+    pub error: Option<String>,
+    pub server_time: String,
+}
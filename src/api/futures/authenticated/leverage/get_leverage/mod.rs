@@ -33,7 +33,6 @@ impl Endpoint for GetLeveragePreferences {
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-
 pub struct LeveragePreferences {
     pub max_leverage: f64,
     pub symbol: String,
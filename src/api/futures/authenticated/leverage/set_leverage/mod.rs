@@ -38,7 +38,7 @@ impl Endpoint for SetLeveragePreferences {
 
     fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
         let serialized_params: serde_json::Value =
-            serde_json::to_value(&self).expect("Serialization failed");
+            serde_json::to_value(self).expect("Serialization failed");
 
         match serialized_params {
             serde_json::Value::Object(params) => {
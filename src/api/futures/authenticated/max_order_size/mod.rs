@@ -0,0 +1,99 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_aux::prelude::deserialize_option_number_from_string;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    params::{query_value, QueryParams},
+};
+
+use super::send_order::{OrderType, SendOrder};
+
+/// Maximum order size the available margin allows, in both directions.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"), setter(into))]
+pub struct MaxOrderSize {
+    pub order_type: OrderType,
+    pub symbol: String,
+    #[builder(setter(strip_option), default)]
+    pub limit_price: Option<f64>,
+}
+
+impl MaxOrderSizeBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.symbol.as_ref().is_some_and(|symbol| symbol.is_empty()) {
+            return Err("symbol cannot be empty".to_string());
+        }
+
+        let needs_limit_price = matches!(
+            self.order_type,
+            Some(OrderType::Limit | OrderType::Post | OrderType::Ioc)
+        );
+
+        if needs_limit_price && self.limit_price.flatten().is_none() {
+            return Err("limit_price is required with Limit, Post or Ioc order type".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl MaxOrderSize {
+    pub fn builder() -> MaxOrderSizeBuilder {
+        MaxOrderSizeBuilder::default()
+    }
+}
+
+impl From<&SendOrder> for MaxOrderSize {
+    fn from(order: &SendOrder) -> Self {
+        Self {
+            order_type: order.order_type.clone(),
+            symbol: order.symbol.clone(),
+            limit_price: order.limit_price,
+        }
+    }
+}
+
+impl Endpoint for MaxOrderSize {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/initialmargin/maxordersize")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        params
+            .push("orderType", query_value(&self.order_type))
+            .push("symbol", &self.symbol)
+            .push_opt("limitPrice", self.limit_price);
+
+        Some(params)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxOrderSizeResp {
+    pub result: String,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub buy_price: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub max_buy_size: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub sell_price: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub max_sell_size: Option<f64>,
+    pub server_time: String,
+}
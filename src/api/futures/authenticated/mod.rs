@@ -1,7 +1,26 @@
 pub mod accounts;
 pub mod open_positions;
+pub mod open_orders;
+pub mod order_status;
+pub mod fills;
+pub mod fee_volumes;
 pub mod withdrawal;
+pub mod transfer;
+pub mod subaccount;
 pub mod send_order;
+pub mod batch_order;
+pub mod initial_margin;
+pub mod max_order_size;
+pub mod edit_order;
+pub mod cancel_order;
+pub mod cancel_all_orders;
+pub mod cancel_all_orders_after;
 pub mod leverage;
 pub mod pnl_preferences;
+pub mod self_trade_strategy;
+pub mod notifications;
+pub mod unwind_queue;
+pub mod assignment_program;
+pub mod rfq;
 pub mod account_log;
+pub mod history;
\ No newline at end of file
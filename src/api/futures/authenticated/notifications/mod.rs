@@ -0,0 +1,75 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+#[derive(Debug, Clone, Builder)]
+pub struct Notifications {}
+
+impl Notifications {
+    pub fn builder() -> NotificationsBuilder {
+        NotificationsBuilder::default()
+    }
+}
+
+impl Endpoint for Notifications {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/notifications")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationType {
+    NewFeature,
+    BugFix,
+    Settlement,
+    General,
+    ScheduledMaintenance,
+    UnscheduledMaintenance,
+    Maintenance,
+    Market,
+    /// A type this crate does not know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationPriority {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    pub r#type: NotificationType,
+    pub priority: NotificationPriority,
+    pub note: String,
+    pub effective_time: Option<String>,
+    pub expected_downtime_minutes: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationsResp {
+    #[serde(default)]
+    pub notifications: Vec<Notification>,
+    pub result: String,
+    pub server_time: String,
+}
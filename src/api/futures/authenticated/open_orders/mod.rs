@@ -0,0 +1,84 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+use super::send_order::{OrderSide, TriggerSignal};
+
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct OpenOrders {}
+
+impl OpenOrders {
+    pub fn builder() -> OpenOrdersBuilder {
+        OpenOrdersBuilder::default()
+    }
+}
+
+impl Endpoint for OpenOrders {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/openorders")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub enum OpenOrderType {
+    #[serde(rename = "lmt")]
+    Limit,
+    #[serde(rename = "stop")]
+    Stop,
+    #[serde(rename = "take_profit")]
+    TakeProfit,
+    #[serde(rename = "trailing_stop")]
+    TrailingStop,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum OpenOrderStatus {
+    Untouched,
+    PartiallyFilled,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrder {
+    // Kraken made a typo apparently
+    #[serde(rename = "order_id")]
+    pub order_id: String,
+    pub cli_ord_id: Option<String>,
+    pub status: OpenOrderStatus,
+    pub side: OrderSide,
+    pub order_type: OpenOrderType,
+    pub symbol: String,
+    pub limit_price: Option<f64>,
+    pub stop_price: Option<f64>,
+    pub filled_size: f64,
+    pub unfilled_size: Option<f64>,
+    #[serde(default)]
+    pub reduce_only: bool,
+    pub trigger_signal: Option<TriggerSignal>,
+    pub last_update_time: String,
+    pub received_time: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersResp {
+    pub result: String,
+    #[serde(default)]
+    pub open_orders: Vec<OpenOrder>,
+    pub server_time: String,
+}
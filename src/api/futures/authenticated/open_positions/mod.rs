@@ -40,7 +40,6 @@ pub enum Side {
 }
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-
 pub struct OpenPosition {
     pub fill_time: String,
     pub price: f64,
@@ -54,7 +53,6 @@ pub struct OpenPosition {
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-
 pub struct OpenPositionsResp {
     pub result: String,
     pub open_positions: Vec<OpenPosition>,
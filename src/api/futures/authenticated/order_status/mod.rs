@@ -0,0 +1,131 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    params::QueryParams,
+};
+
+use super::send_order::{OrderSide, TriggerSide, TriggerSignalResp};
+
+/// Looks up the status of orders by their ids and/or their client order ids.
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(build_fn(validate = "Self::validate"), setter(into), default)]
+pub struct OrderStatus {
+    pub order_ids: Vec<String>,
+    pub cli_ord_ids: Vec<String>,
+}
+
+impl OrderStatusBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.order_ids.as_ref().map_or(0, Vec::len) == 0
+            && self.cli_ord_ids.as_ref().map_or(0, Vec::len) == 0
+        {
+            return Err("At least one order id or client order id must be set".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl OrderStatus {
+    pub fn builder() -> OrderStatusBuilder {
+        OrderStatusBuilder::default()
+    }
+}
+
+impl Endpoint for OrderStatus {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/orders/status")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+
+        for order_id in &self.order_ids {
+            params.push("orderIds", order_id);
+        }
+
+        for cli_ord_id in &self.cli_ord_ids {
+            params.push("cliOrdIds", cli_ord_id);
+        }
+
+        Some(params)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderKind {
+    Order,
+    TriggerOrder,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatusValue {
+    EnteredBook,
+    FullyExecuted,
+    Rejected,
+    Cancelled,
+    TriggerPlaced,
+    TriggerActivationFailure,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceTriggerOptions {
+    pub trigger_price: f64,
+    pub trigger_side: TriggerSide,
+    pub trigger_signal: TriggerSignalResp,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderDetails {
+    pub r#type: OrderKind,
+    pub order_id: String,
+    pub cli_ord_id: Option<String>,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: f64,
+    pub filled: f64,
+    pub limit_price: Option<f64>,
+    #[serde(default)]
+    pub reduce_only: bool,
+    pub timestamp: String,
+    pub last_update_timestamp: String,
+    pub price_trigger_options: Option<PriceTriggerOptions>,
+    pub trigger_time: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderStatusEntry {
+    pub order: OrderDetails,
+    pub status: OrderStatusValue,
+    pub update_reason: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderStatusResp {
+    pub result: String,
+    #[serde(default)]
+    pub orders: Vec<OrderStatusEntry>,
+    pub server_time: String,
+}
@@ -0,0 +1,45 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+pub use super::open_offers::OfferStatus;
+
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct CancelOffer {
+    pub rfq_uid: String,
+}
+
+impl CancelOffer {
+    pub fn builder() -> CancelOfferBuilder {
+        CancelOfferBuilder::default()
+    }
+}
+
+impl Endpoint for CancelOffer {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> String {
+        format!("/derivatives/api/v3/rfqs/{}/cancel-offer", self.rfq_uid)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOfferResp {
+    pub status: OfferStatus,
+    pub result: String,
+    pub server_time: String,
+}
@@ -0,0 +1,82 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+pub use super::open_offers::OfferStatus;
+
+/// Places an offer on an RFQ, at least one of `bid` and `ask` must be set.
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(
+    build_fn(validate = "Self::validate"),
+    setter(strip_option, into),
+    default
+)]
+pub struct CreateOffer {
+    #[serde(skip)]
+    pub rfq_uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ask: Option<f64>,
+}
+
+impl CreateOfferBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.rfq_uid.as_deref().unwrap_or_default().is_empty() {
+            return Err("Field rfq_uid is required".to_string());
+        }
+
+        if self.bid.flatten().is_none() && self.ask.flatten().is_none() {
+            return Err("Either bid or ask must be set".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl CreateOffer {
+    pub fn builder() -> CreateOfferBuilder {
+        CreateOfferBuilder::default()
+    }
+}
+
+impl Endpoint for CreateOffer {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        format!("/derivatives/api/v3/rfqs/{}/create-offer", self.rfq_uid)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOfferResp {
+    pub status: OfferStatus,
+    pub result: String,
+    pub server_time: String,
+}
@@ -0,0 +1,5 @@
+pub mod open_rfqs;
+pub mod open_offers;
+pub mod create_offer;
+pub mod replace_offer;
+pub mod cancel_offer;
\ No newline at end of file
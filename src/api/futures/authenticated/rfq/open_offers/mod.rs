@@ -0,0 +1,71 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_aux::prelude::deserialize_option_number_from_string;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+/// Offers of the account on open RFQs.
+#[derive(Debug, Clone, Builder)]
+pub struct OpenOffers {}
+
+impl OpenOffers {
+    pub fn builder() -> OpenOffersBuilder {
+        OpenOffersBuilder::default()
+    }
+}
+
+impl Endpoint for OpenOffers {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/rfqs/open-offers")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqOffer {
+    pub rfq_uid: String,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub bid: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub ask: Option<f64>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// Outcome of an offer request, Kraken answers with a `result` of success even when the offer
+/// itself was rejected.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum OfferStatus {
+    Created,
+    Replaced,
+    Cancelled,
+    RfqNotFound,
+    RfqExpired,
+    OfferNotFound,
+    InvalidPrice,
+    InvalidSide,
+    Rejected,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOffersResp {
+    #[serde(default)]
+    pub offers: Vec<RfqOffer>,
+    pub result: String,
+    pub server_time: String,
+}
@@ -0,0 +1,75 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_aux::prelude::deserialize_number_from_string;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    futures::authenticated::send_order::OrderSide,
+};
+
+/// Block trade requests for quote that are currently open for offers.
+#[derive(Debug, Clone, Builder)]
+pub struct OpenRfqs {}
+
+impl OpenRfqs {
+    pub fn builder() -> OpenRfqsBuilder {
+        OpenRfqsBuilder::default()
+    }
+}
+
+impl Endpoint for OpenRfqs {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/rfqs")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RfqStatus {
+    Open,
+    Filled,
+    Cancelled,
+    Expired,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqLeg {
+    pub symbol: String,
+    pub side: Option<OrderSide>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub ratio: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Rfq {
+    pub rfq_uid: String,
+    pub status: Option<RfqStatus>,
+    #[serde(default)]
+    pub legs: Vec<RfqLeg>,
+    pub created_at: String,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenRfqsResp {
+    #[serde(default)]
+    pub rfqs: Vec<Rfq>,
+    pub result: String,
+    pub server_time: String,
+}
@@ -0,0 +1,46 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SelfTradeStrategy {
+    #[default]
+    RejectTaker,
+    CancelMakerSelf,
+    CancelMakerChild,
+}
+
+#[derive(Debug, Clone, Builder)]
+pub struct GetSelfTradeStrategy {}
+
+impl GetSelfTradeStrategy {
+    pub fn builder() -> GetSelfTradeStrategyBuilder {
+        GetSelfTradeStrategyBuilder::default()
+    }
+}
+
+impl Endpoint for GetSelfTradeStrategy {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/self-trade-strategy")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SelfTradeStrategyResp {
+    pub strategy: SelfTradeStrategy,
+}
@@ -0,0 +1,2 @@
+pub mod set_self_trade_strategy;
+pub mod get_self_trade_strategy;
\ No newline at end of file
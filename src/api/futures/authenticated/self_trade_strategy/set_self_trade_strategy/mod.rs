@@ -0,0 +1,50 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+pub use super::get_self_trade_strategy::{SelfTradeStrategy, SelfTradeStrategyResp};
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct SetSelfTradeStrategy {
+    pub strategy: SelfTradeStrategy,
+}
+
+impl SetSelfTradeStrategy {
+    pub fn builder() -> SetSelfTradeStrategyBuilder {
+        SetSelfTradeStrategyBuilder::default()
+    }
+}
+
+impl Endpoint for SetSelfTradeStrategy {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/self-trade-strategy")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
@@ -32,9 +32,9 @@ pub enum OrderType {
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub enum OrderSide {
     #[default]
-    #[serde(rename = "buy")]
+    #[serde(rename = "buy", alias = "Buy")]
     Buy,
-    #[serde(rename = "sell")]
+    #[serde(rename = "sell", alias = "Sell")]
     Sell,
 }
 
@@ -46,7 +46,7 @@ pub enum LimitPriceOffsetUnit {
     Percent,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TriggerSignal {
     #[serde(rename = "mark")]
     Mark,
@@ -145,7 +145,7 @@ impl Endpoint for SendOrder {
 
     fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
         let serialized_params: serde_json::Value =
-            serde_json::to_value(&self).expect("Serialization failed");
+            serde_json::to_value(self).expect("Serialization failed");
 
         match serialized_params {
             serde_json::Value::Object(params) => {
@@ -168,21 +168,25 @@ pub enum EventType {
 
 #[derive(Debug, Deserialize, Clone)]
 pub enum OrderTypeResp {
-    #[serde(rename = "lmt")]
+    #[serde(rename = "lmt", alias = "Limit")]
     Limit,
-    #[serde(rename = "ioc")]
+    #[serde(rename = "ioc", alias = "IoC")]
     Ioc,
-    #[serde(rename = "post")]
+    #[serde(rename = "post", alias = "Post")]
     Post,
-    #[serde(rename = "liquidation")]
+    #[serde(rename = "mkt", alias = "Market")]
+    Market,
+    #[serde(rename = "liquidation", alias = "Liquidation")]
     Liquidation,
-    #[serde(rename = "assignment")]
+    #[serde(rename = "assignment", alias = "Assignment")]
     Assignment,
-    #[serde(rename = "stp")]
+    #[serde(rename = "stp", alias = "Stop")]
     Stop,
-    #[serde(rename = "unwind")]
+    #[serde(rename = "take_profit", alias = "TakeProfit")]
+    TakeProfit,
+    #[serde(rename = "unwind", alias = "Unwind")]
     Unwind,
-    #[serde(rename = "block")]
+    #[serde(rename = "block", alias = "Block")]
     Block,
 }
 
@@ -206,15 +210,20 @@ pub struct Order {
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum TriggerSide {
+    #[serde(alias = "TRIGGER_ABOVE")]
     TriggerAbove,
+    #[serde(alias = "TRIGGER_BELOW")]
     TriggerBelow,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum TriggerSignalResp {
+    #[serde(alias = "MARK_PRICE")]
     MarkPrice,
+    #[serde(alias = "LAST_PRICE")]
     LastPrice,
+    #[serde(alias = "SPOT_PRICE")]
     SpotPrice,
 }
 
@@ -222,7 +231,7 @@ pub enum TriggerSignalResp {
 #[serde(rename_all = "camelCase")]
 pub struct OrderTrigger {
     pub client_id: Option<String>,
-    pub timlast_update_timestampestamp: String,
+    pub last_update_timestamp: String,
     pub limit_price: Option<f64>,
     pub quantity: Option<f64>,
 
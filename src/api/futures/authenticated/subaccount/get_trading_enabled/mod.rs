@@ -0,0 +1,44 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct GetTradingEnabled {
+    pub subaccount_uid: String,
+}
+
+impl GetTradingEnabled {
+    pub fn builder() -> GetTradingEnabledBuilder {
+        GetTradingEnabledBuilder::default()
+    }
+}
+
+impl Endpoint for GetTradingEnabled {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        format!(
+            "/derivatives/api/v3/subaccount/{}/trading-enabled",
+            self.subaccount_uid
+        )
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingEnabledResp {
+    pub trading_enabled: bool,
+}
@@ -0,0 +1,4 @@
+pub mod subaccounts;
+pub mod subaccount_transfer;
+pub mod get_trading_enabled;
+pub mod set_trading_enabled;
\ No newline at end of file
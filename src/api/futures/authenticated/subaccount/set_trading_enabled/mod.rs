@@ -0,0 +1,50 @@
+use derive_builder::Builder;
+use http::Method;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    params::QueryParams,
+};
+
+pub use super::get_trading_enabled::TradingEnabledResp;
+
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct SetTradingEnabled {
+    pub subaccount_uid: String,
+    pub trading_enabled: bool,
+}
+
+impl SetTradingEnabled {
+    pub fn builder() -> SetTradingEnabledBuilder {
+        SetTradingEnabledBuilder::default()
+    }
+}
+
+impl Endpoint for SetTradingEnabled {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> String {
+        format!(
+            "/derivatives/api/v3/subaccount/{}/trading-enabled",
+            self.subaccount_uid
+        )
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        params.push("tradingEnabled", self.trading_enabled);
+
+        Some(params)
+    }
+}
@@ -0,0 +1,62 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+/// Transfers funds between the margin accounts of the master account and its subaccounts.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountTransfer {
+    pub from_user: String,
+    pub to_user: String,
+    pub from_account: String,
+    pub to_account: String,
+    pub unit: String,
+    pub amount: String,
+}
+
+impl SubaccountTransfer {
+    pub fn builder() -> SubaccountTransferBuilder {
+        SubaccountTransferBuilder::default()
+    }
+}
+
+impl Endpoint for SubaccountTransfer {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/transfer/subaccount")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountTransferResp {
+    pub result: String,
+    pub server_time: String,
+}
@@ -0,0 +1,67 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Subaccounts {}
+
+impl Subaccounts {
+    pub fn builder() -> SubaccountsBuilder {
+        SubaccountsBuilder::default()
+    }
+}
+
+impl Endpoint for Subaccounts {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/subaccounts")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HoldingAccount {
+    pub currency: String,
+    pub amount: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountFuturesAccount {
+    pub name: String,
+    pub available_margin: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Subaccount {
+    pub account_uid: String,
+    pub email: String,
+    pub full_name: Option<String>,
+    #[serde(default)]
+    pub holding_accounts: Vec<HoldingAccount>,
+    #[serde(default)]
+    pub futures_accounts: Vec<SubaccountFuturesAccount>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountsResp {
+    pub result: String,
+    pub master_account_uid: String,
+    #[serde(default)]
+    pub subaccounts: Vec<Subaccount>,
+    pub server_time: String,
+}
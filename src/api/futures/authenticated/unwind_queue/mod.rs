@@ -0,0 +1,49 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::{Endpoint, EndpointType};
+
+/// Percentile of the open positions in the auto-deleveraging queue.
+#[derive(Debug, Clone, Builder)]
+pub struct UnwindQueue {}
+
+impl UnwindQueue {
+    pub fn builder() -> UnwindQueueBuilder {
+        UnwindQueueBuilder::default()
+    }
+}
+
+impl Endpoint for UnwindQueue {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/unwindqueue")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct UnwindQueuePosition {
+    pub symbol: String,
+    /// 20, 40, 60, 80 or 100, the higher the sooner the position gets deleveraged.
+    pub percentile: u8,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UnwindQueueResp {
+    #[serde(default)]
+    pub queue: Vec<UnwindQueuePosition>,
+    pub result: String,
+    pub server_time: String,
+}
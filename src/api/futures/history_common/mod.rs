@@ -0,0 +1,182 @@
+use serde::Deserialize;
+use serde_aux::prelude::{deserialize_number_from_string, deserialize_option_number_from_string};
+
+use crate::api::{
+    futures::authenticated::send_order::{
+        Order, OrderSide, OrderTrigger, OrderTypeResp, TriggerSide, TriggerSignalResp,
+    },
+    params::QueryParams,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HistorySort {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl std::fmt::Display for HistorySort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistorySort::Asc => write!(f, "asc"),
+            HistorySort::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+/// Query parameters shared by the history endpoints. Timestamps are epoch milliseconds.
+pub(crate) fn push_history_params<'a>(
+    params: &mut QueryParams<'a>,
+    since: Option<i64>,
+    before: Option<i64>,
+    sort: Option<HistorySort>,
+    continuation_token: Option<&'a str>,
+    count: Option<u64>,
+) {
+    params
+        .push_opt("since", since)
+        .push_opt("before", before)
+        .push_opt("sort", sort)
+        .push_opt("continuation_token", continuation_token)
+        .push_opt("count", count);
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct HistoryElement<E> {
+    pub uid: String,
+    pub timestamp: i64,
+    pub event: E,
+}
+
+/// A page of history events, `continuation_token` is set when more pages are available.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryResp<E> {
+    pub account_uid: Option<String>,
+    pub len: u64,
+    pub server_time: Option<String>,
+    pub elements: Vec<HistoryElement<E>>,
+    pub continuation_token: Option<String>,
+}
+
+/// An order as reported by the history API, which names and encodes its fields differently
+/// from the `sendorder` events.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryOrder {
+    pub uid: String,
+    pub account_uid: Option<String>,
+    pub client_id: Option<String>,
+    pub tradeable: String,
+    pub direction: OrderSide,
+    pub order_type: OrderTypeResp,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub quantity: f64,
+    /// Absent from the orders of public market executions.
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub filled: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub limit_price: Option<f64>,
+    pub reduce_only: bool,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub timestamp: i64,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub last_update_timestamp: Option<i64>,
+}
+
+/// A missing `filled` becomes 0.
+impl From<HistoryOrder> for Order {
+    fn from(order: HistoryOrder) -> Self {
+        Order {
+            cli_ord_id: order.client_id,
+            filled: order.filled.unwrap_or_default(),
+            limit_price: order.limit_price,
+            order_id: Some(order.uid),
+            quantity: order.quantity,
+            reduce_only: order.reduce_only,
+            side: order.direction,
+            symbol: order.tradeable,
+            timestamp: order.timestamp.to_string(),
+            r#type: order.order_type,
+        }
+    }
+}
+
+/// A trigger order as reported by the history API.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryOrderTrigger {
+    pub uid: String,
+    pub account_uid: Option<String>,
+    pub client_id: Option<String>,
+    pub tradeable: String,
+    pub direction: OrderSide,
+    pub order_type: OrderTypeResp,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub quantity: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub limit_price: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub trigger_price: Option<f64>,
+    pub trigger_side: Option<TriggerSide>,
+    pub trigger_signal: Option<TriggerSignalResp>,
+    pub reduce_only: bool,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub timestamp: i64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub last_update_timestamp: i64,
+    pub start_time: Option<String>,
+}
+
+impl From<HistoryOrderTrigger> for OrderTrigger {
+    fn from(trigger: HistoryOrderTrigger) -> Self {
+        OrderTrigger {
+            client_id: trigger.client_id,
+            last_update_timestamp: trigger.last_update_timestamp.to_string(),
+            limit_price: trigger.limit_price,
+            quantity: trigger.quantity,
+            reduce_only: trigger.reduce_only,
+            side: trigger.direction,
+            start_time: trigger.start_time,
+            symbol: trigger.tradeable,
+            timestamp: trigger.timestamp.to_string(),
+            trigger_price: trigger.trigger_price,
+            trigger_side: trigger.trigger_side,
+            trigger_signal: trigger.trigger_signal,
+            uid: trigger.uid,
+            r#type: trigger.order_type,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Execution {
+    pub uid: String,
+    pub maker_order: HistoryOrder,
+    pub taker_order: HistoryOrder,
+    pub timestamp: i64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub quantity: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub price: f64,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub mark_price: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub limit_filled: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub usd_value: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionDetails {
+    pub execution: Execution,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub taker_reduced_quantity: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub enum ExecutionEvent {
+    Execution(ExecutionDetails),
+}
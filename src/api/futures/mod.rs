@@ -1,2 +1,3 @@
 pub mod authenticated;
 pub mod public;
+pub mod history_common;
@@ -1,25 +1,97 @@
 use derive_builder::Builder;
 use http::Method;
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_aux::prelude::deserialize_option_number_from_string;
 
 use crate::api::{
     endpoint::{Endpoint, EndpointType},
     params::QueryParams,
 };
 
-#[derive(Debug, Clone, Copy, Builder)]
-#[builder(setter(strip_option))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalyticsType {
+    OpenInterest,
+    AggressorDifferential,
+    TradeVolume,
+    TradeCount,
+    LiquidationVolume,
+    RollingVolatility,
+    LongShortRatio,
+    Cvd,
+    TopTraders,
+    Orderbook,
+    Spreads,
+    Liquidity,
+    Slippage,
+    FutureBasis,
+    LiquidityPool,
+}
+
+impl std::fmt::Display for AnalyticsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalyticsType::OpenInterest => write!(f, "open-interest"),
+            AnalyticsType::AggressorDifferential => write!(f, "aggressor-differential"),
+            AnalyticsType::TradeVolume => write!(f, "trade-volume"),
+            AnalyticsType::TradeCount => write!(f, "trade-count"),
+            AnalyticsType::LiquidationVolume => write!(f, "liquidation-volume"),
+            AnalyticsType::RollingVolatility => write!(f, "rolling-volatility"),
+            AnalyticsType::LongShortRatio => write!(f, "long-short-ratio"),
+            AnalyticsType::Cvd => write!(f, "cvd"),
+            AnalyticsType::TopTraders => write!(f, "top-traders"),
+            AnalyticsType::Orderbook => write!(f, "orderbook"),
+            AnalyticsType::Spreads => write!(f, "spreads"),
+            AnalyticsType::Liquidity => write!(f, "liquidity"),
+            AnalyticsType::Slippage => write!(f, "slippage"),
+            AnalyticsType::FutureBasis => write!(f, "future-basis"),
+            AnalyticsType::LiquidityPool => write!(f, "liquidity-pool"),
+        }
+    }
+}
+
+/// Analytics of a symbol, or of the liquidity pool which takes no symbol. `interval` is in
+/// seconds and `since`/`to` are epoch seconds.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"), setter(strip_option))]
 pub struct Analytics {
+    #[builder(setter(into), default)]
+    symbol: Option<String>,
+    #[builder(default = "AnalyticsType::LiquidityPool")]
+    analytics_type: AnalyticsType,
     interval: u64,
     since: i64,
     #[builder(default)]
     to: Option<u64>,
 }
 
+impl AnalyticsBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let analytics_type = self.analytics_type.unwrap_or(AnalyticsType::LiquidityPool);
+        let has_symbol = matches!(self.symbol, Some(Some(_)));
+
+        match analytics_type {
+            AnalyticsType::LiquidityPool if has_symbol => {
+                Err("liquidity-pool analytics do not take a symbol".to_string())
+            }
+            AnalyticsType::LiquidityPool => Ok(()),
+            _ if !has_symbol => Err(format!("{analytics_type} analytics require a symbol")),
+            _ => Ok(()),
+        }
+    }
+}
+
 impl Analytics {
     pub fn builder() -> AnalyticsBuilder {
         AnalyticsBuilder::default()
     }
+
+    /// A builder for the analytics type decoded by `D`.
+    pub fn builder_for<D: AnalyticsData>() -> AnalyticsBuilder {
+        let mut builder = AnalyticsBuilder::default();
+        builder.analytics_type(D::ANALYTICS_TYPE);
+
+        builder
+    }
 }
 
 impl Endpoint for Analytics {
@@ -28,10 +100,16 @@ impl Endpoint for Analytics {
     }
 
     fn endpoint(&self) -> String {
-        "/api/charts/v1/analytics/liquidity-pool".to_owned()
+        match &self.symbol {
+            Some(symbol) => format!(
+                "/api/charts/v1/analytics/{}/{}",
+                symbol, self.analytics_type
+            ),
+            None => format!("/api/charts/v1/analytics/{}", self.analytics_type),
+        }
     }
 
-    fn parameters(&self) -> Option<QueryParams> {
+    fn parameters(&self) -> Option<QueryParams<'_>> {
         let mut params = QueryParams::default();
         params.push("interval", self.interval.to_string());
         params.push("since", self.since.to_string());
@@ -48,6 +126,267 @@ impl Endpoint for Analytics {
     }
 }
 
+/// Values of a series, one per timestamp. Kraken sends them as numbers or strings and uses
+/// `null` for buckets without data.
+pub type Series = Vec<Option<f64>>;
+
+fn deserialize_series<'de, D>(deserializer: D) -> Result<Series, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Point(#[serde(deserialize_with = "deserialize_option_number_from_string")] Option<f64>);
+
+    let points: Vec<Point> = Deserialize::deserialize(deserializer)?;
+    Ok(points.into_iter().map(|Point(value)| value).collect())
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub open: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub high: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub low: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub close: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AggressorDifferentialData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub buy_volume: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub sell_volume: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub buy_count: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub sell_count: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeVolumeData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub volume: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeCountData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub count: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidationVolumeData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub long: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub short: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingVolatilityData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub volatility: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatioData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub ratio: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub long_percent: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub short_percent: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CvdData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub buy_volume: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub sell_volume: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub cvd: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TopTradersData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub long_percent: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub short_percent: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub ratio: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderbookData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub bid_volume: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub ask_volume: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadsData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub spread: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidityData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub bid: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub ask: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SlippageData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub buy: Series,
+    #[serde(deserialize_with = "deserialize_series")]
+    pub sell: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FutureBasisData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub basis: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidityPoolData {
+    #[serde(deserialize_with = "deserialize_series")]
+    pub usd_value: Series,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyticsResult<D> {
+    pub timestamp: Vec<i64>,
+    pub data: D,
+}
+
+impl<D> AnalyticsResult<D> {
+    /// Pairs each timestamp with the value of `series` at the same index, missing values are
+    /// `None`.
+    pub fn aligned<'a>(
+        &'a self,
+        series: &'a Series,
+    ) -> impl Iterator<Item = (i64, Option<f64>)> + 'a {
+        self.timestamp
+            .iter()
+            .enumerate()
+            .map(|(i, timestamp)| (*timestamp, series.get(i).copied().flatten()))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AnalyticsSeriesResp<D> {
+    pub result: AnalyticsResult<D>,
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
+/// Series data of an analytics type, `ANALYTICS_TYPE` is the type it decodes.
+pub trait AnalyticsData: DeserializeOwned {
+    const ANALYTICS_TYPE: AnalyticsType;
+}
+
+impl AnalyticsData for OpenInterestData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::OpenInterest;
+}
+
+impl AnalyticsData for AggressorDifferentialData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::AggressorDifferential;
+}
+
+impl AnalyticsData for TradeVolumeData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::TradeVolume;
+}
+
+impl AnalyticsData for TradeCountData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::TradeCount;
+}
+
+impl AnalyticsData for LiquidationVolumeData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::LiquidationVolume;
+}
+
+impl AnalyticsData for RollingVolatilityData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::RollingVolatility;
+}
+
+impl AnalyticsData for LongShortRatioData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::LongShortRatio;
+}
+
+impl AnalyticsData for CvdData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::Cvd;
+}
+
+impl AnalyticsData for TopTradersData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::TopTraders;
+}
+
+impl AnalyticsData for OrderbookData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::Orderbook;
+}
+
+impl AnalyticsData for SpreadsData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::Spreads;
+}
+
+impl AnalyticsData for LiquidityData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::Liquidity;
+}
+
+impl AnalyticsData for SlippageData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::Slippage;
+}
+
+impl AnalyticsData for FutureBasisData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::FutureBasis;
+}
+
+impl AnalyticsData for LiquidityPoolData {
+    const ANALYTICS_TYPE: AnalyticsType = AnalyticsType::LiquidityPool;
+}
+
+pub type OpenInterestResp = AnalyticsSeriesResp<OpenInterestData>;
+pub type AggressorDifferentialResp = AnalyticsSeriesResp<AggressorDifferentialData>;
+pub type TradeVolumeResp = AnalyticsSeriesResp<TradeVolumeData>;
+pub type TradeCountResp = AnalyticsSeriesResp<TradeCountData>;
+pub type LiquidationVolumeResp = AnalyticsSeriesResp<LiquidationVolumeData>;
+pub type RollingVolatilityResp = AnalyticsSeriesResp<RollingVolatilityData>;
+pub type LongShortRatioResp = AnalyticsSeriesResp<LongShortRatioData>;
+pub type CvdResp = AnalyticsSeriesResp<CvdData>;
+pub type TopTradersResp = AnalyticsSeriesResp<TopTradersData>;
+pub type OrderbookResp = AnalyticsSeriesResp<OrderbookData>;
+pub type SpreadsResp = AnalyticsSeriesResp<SpreadsData>;
+pub type LiquidityResp = AnalyticsSeriesResp<LiquidityData>;
+pub type SlippageResp = AnalyticsSeriesResp<SlippageData>;
+pub type FutureBasisResp = AnalyticsSeriesResp<FutureBasisData>;
+pub type LiquidityPoolResp = AnalyticsSeriesResp<LiquidityPoolData>;
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AnalyticsRespData {
@@ -61,6 +400,7 @@ pub struct AnalyticsRespWrapped {
     pub data: AnalyticsRespData,
 }
 
+/// The liquidity pool response with the values left as strings, see `LiquidityPoolResp`.
 #[derive(Debug, Deserialize, Clone)]
 pub struct AnalyticsResp {
     pub result: AnalyticsRespWrapped,
@@ -0,0 +1,204 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_aux::prelude::deserialize_number_from_string;
+
+use crate::api::{
+    client::{AsyncClient, Client},
+    common::TimeFrame,
+    endpoint::{Endpoint, EndpointType},
+    error::ApiError,
+    params::QueryParams,
+    query::{AsyncQuery, Query},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartTickType {
+    Trade,
+    Mark,
+    Spot,
+}
+
+impl std::fmt::Display for ChartTickType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChartTickType::Trade => write!(f, "trade"),
+            ChartTickType::Mark => write!(f, "mark"),
+            ChartTickType::Spot => write!(f, "spot"),
+        }
+    }
+}
+
+/// Candle resolutions supported by charts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartResolution {
+    OneMin,
+    FiveMins,
+    FifteenMins,
+    ThirtyMins,
+    OneHour,
+    FourHours,
+    TwelveHours,
+    OneDay,
+    OneWeek,
+}
+
+impl std::fmt::Display for ChartResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChartResolution::OneMin => write!(f, "1m"),
+            ChartResolution::FiveMins => write!(f, "5m"),
+            ChartResolution::FifteenMins => write!(f, "15m"),
+            ChartResolution::ThirtyMins => write!(f, "30m"),
+            ChartResolution::OneHour => write!(f, "1h"),
+            ChartResolution::FourHours => write!(f, "4h"),
+            ChartResolution::TwelveHours => write!(f, "12h"),
+            ChartResolution::OneDay => write!(f, "1d"),
+            ChartResolution::OneWeek => write!(f, "1w"),
+        }
+    }
+}
+
+impl TryFrom<TimeFrame> for ChartResolution {
+    type Error = String;
+
+    fn try_from(time_frame: TimeFrame) -> Result<Self, Self::Error> {
+        match time_frame {
+            TimeFrame::OneMin => Ok(ChartResolution::OneMin),
+            TimeFrame::FiveMins => Ok(ChartResolution::FiveMins),
+            TimeFrame::FifteenMins => Ok(ChartResolution::FifteenMins),
+            TimeFrame::ThirtyMins => Ok(ChartResolution::ThirtyMins),
+            TimeFrame::OneHour => Ok(ChartResolution::OneHour),
+            TimeFrame::TwelveHours => Ok(ChartResolution::TwelveHours),
+            TimeFrame::OneDay => Ok(ChartResolution::OneDay),
+            TimeFrame::OneWeek => Ok(ChartResolution::OneWeek),
+            _ => Err(format!(
+                "Resolution {time_frame} is not supported by charts"
+            )),
+        }
+    }
+}
+
+/// OHLC candles of a futures or index symbol. `from` and `to` are epoch seconds.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct Charts {
+    pub tick_type: ChartTickType,
+    pub symbol: String,
+    pub resolution: ChartResolution,
+    #[builder(default)]
+    pub from: Option<i64>,
+    #[builder(default)]
+    pub to: Option<i64>,
+}
+
+impl Charts {
+    pub fn builder() -> ChartsBuilder {
+        ChartsBuilder::default()
+    }
+}
+
+impl Endpoint for Charts {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        format!(
+            "/api/charts/v1/{}/{}/{}",
+            self.tick_type, self.symbol, self.resolution,
+        )
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        params.push_opt("from", self.from).push_opt("to", self.to);
+
+        Some(params)
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Candle {
+    /// Epoch milliseconds.
+    pub time: i64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub open: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub high: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub low: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub close: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub volume: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ChartsResp {
+    pub candles: Vec<Candle>,
+    pub more_candles: bool,
+}
+
+impl ChartsResp {
+    /// The `from` value of the next page, if Kraken reported more candles.
+    pub fn next_from(&self) -> Option<i64> {
+        if !self.more_candles {
+            return None;
+        }
+
+        self.candles.last().map(|candle| candle.time / 1000 + 1)
+    }
+}
+
+/// Fetches all the candles of the range, following `more_candles`.
+pub fn candles_history<C>(
+    client: &C,
+    mut endpoint: Charts,
+) -> Result<Vec<Candle>, ApiError<C::Error>>
+where
+    C: for<'a> Client<'a>,
+{
+    let mut candles = Vec::new();
+
+    loop {
+        let rsp: ChartsResp = endpoint.query(client)?;
+        let next_from = rsp.next_from();
+        candles.extend(rsp.candles);
+
+        match next_from {
+            Some(from) => endpoint.from = Some(from),
+            None => break,
+        }
+    }
+
+    Ok(candles)
+}
+
+/// Fetches all the candles of the range asynchronously, see `candles_history`.
+pub async fn candles_history_async<C>(
+    client: &C,
+    mut endpoint: Charts,
+) -> Result<Vec<Candle>, ApiError<C::Error>>
+where
+    C: for<'a> AsyncClient<'a> + Sync,
+{
+    let mut candles = Vec::new();
+
+    loop {
+        let rsp: ChartsResp = endpoint.query_async(client).await?;
+        let next_from = rsp.next_from();
+        candles.extend(rsp.candles);
+
+        match next_from {
+            Some(from) => endpoint.from = Some(from),
+            None => break,
+        }
+    }
+
+    Ok(candles)
+}
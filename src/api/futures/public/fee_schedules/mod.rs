@@ -0,0 +1,99 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    futures::authenticated::fee_volumes::FeeVolumesResp,
+};
+
+use super::instruments::Instrument;
+
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct FeeSchedules {}
+
+impl FeeSchedules {
+    pub fn builder() -> FeeSchedulesBuilder {
+        FeeSchedulesBuilder::default()
+    }
+}
+
+impl Endpoint for FeeSchedules {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/derivatives/api/v3/feeschedules")
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeTier {
+    /// Maker fee in percent, `0.02` is 0.02%.
+    pub maker_fee: f64,
+    /// Taker fee in percent.
+    pub taker_fee: f64,
+    /// Minimum 30 day USD volume to reach the tier.
+    pub usd_volume: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeSchedule {
+    pub uid: String,
+    pub name: String,
+    #[serde(default)]
+    pub tiers: Vec<FeeTier>,
+}
+
+impl FeeSchedule {
+    /// Returns the highest tier reached with the given 30 day USD volume.
+    pub fn tier_for_volume(&self, usd_volume: f64) -> Option<&FeeTier> {
+        self.tiers
+            .iter()
+            .filter(|tier| tier.usd_volume <= usd_volume)
+            .max_by(|a, b| a.usd_volume.total_cmp(&b.usd_volume))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeSchedulesResp {
+    pub result: String,
+    #[serde(default)]
+    pub fee_schedules: Vec<FeeSchedule>,
+    pub server_time: String,
+}
+
+impl FeeSchedulesResp {
+    pub fn schedule(&self, uid: &str) -> Option<&FeeSchedule> {
+        self.fee_schedules.iter().find(|schedule| schedule.uid == uid)
+    }
+
+    /// Returns the fee schedule an instrument refers to through its `fee_schedule_uid`.
+    pub fn schedule_for_instrument(&self, instrument: &Instrument) -> Option<&FeeSchedule> {
+        self.schedule(instrument.fee_schedule_uid.as_deref()?)
+    }
+
+    /// Returns the fee tier currently applied to an instrument, given the account volumes.
+    pub fn effective_tier(
+        &self,
+        instrument: &Instrument,
+        volumes: &FeeVolumesResp,
+    ) -> Option<&FeeTier> {
+        let schedule = self.schedule_for_instrument(instrument)?;
+        let usd_volume = volumes
+            .volumes_by_fee_schedule
+            .get(&schedule.uid)
+            .copied()
+            .unwrap_or_default();
+
+        schedule.tier_for_volume(usd_volume)
+    }
+}
@@ -32,7 +32,7 @@ impl Endpoint for HistoricalFundingRates {
         EndpointType::Futures
     }
 
-    fn parameters(&self) -> Option<QueryParams> {
+    fn parameters(&self) -> Option<QueryParams<'_>> {
         let mut params = QueryParams::default();
         params.push("symbol", self.symbol.to_string());
 
@@ -50,7 +50,6 @@ pub enum Side {
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-
 pub struct FundingRate {
     pub funding_rate: f64,
     pub relative_funding_rate: f64,
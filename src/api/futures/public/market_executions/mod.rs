@@ -0,0 +1,61 @@
+use derive_builder::Builder;
+use http::Method;
+
+use crate::api::{
+    endpoint::{Endpoint, EndpointType},
+    futures::history_common::{push_history_params, ExecutionEvent, HistoryResp, HistorySort},
+    params::QueryParams,
+};
+
+/// Public trade history of a market, pass the `continuation_token` of a page to get the next one.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct MarketExecutions {
+    pub symbol: String,
+    #[builder(default)]
+    pub since: Option<i64>,
+    #[builder(default)]
+    pub before: Option<i64>,
+    #[builder(default)]
+    pub sort: Option<HistorySort>,
+    #[builder(default)]
+    pub continuation_token: Option<String>,
+    #[builder(default)]
+    pub count: Option<u64>,
+}
+
+impl MarketExecutions {
+    pub fn builder() -> MarketExecutionsBuilder {
+        MarketExecutionsBuilder::default()
+    }
+}
+
+impl Endpoint for MarketExecutions {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        format!("/api/history/v2/market/{}/executions", self.symbol)
+    }
+
+    fn endpoint_type(&self) -> EndpointType {
+        EndpointType::Futures
+    }
+
+    fn parameters(&self) -> Option<QueryParams<'_>> {
+        let mut params = QueryParams::default();
+        push_history_params(
+            &mut params,
+            self.since,
+            self.before,
+            self.sort,
+            self.continuation_token.as_deref(),
+            self.count,
+        );
+
+        Some(params)
+    }
+}
+
+pub type MarketExecutionsResp = HistoryResp<ExecutionEvent>;
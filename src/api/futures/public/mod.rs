@@ -1,6 +1,9 @@
 pub mod analytics;
+pub mod charts;
+pub mod fee_schedules;
 pub mod historical_fuding_rates;
 pub mod instruments;
+pub mod market_executions;
 pub mod orderbook;
 pub mod ticker;
-pub mod tickers;
+pub mod tickers;
\ No newline at end of file
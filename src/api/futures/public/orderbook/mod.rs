@@ -32,7 +32,7 @@ impl Endpoint for OrderBook {
         EndpointType::Futures
     }
 
-    fn parameters(&self) -> Option<QueryParams> {
+    fn parameters(&self) -> Option<QueryParams<'_>> {
         let mut params = QueryParams::default();
         params.push("symbol", self.symbol.to_string());
 
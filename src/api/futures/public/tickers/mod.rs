@@ -22,9 +22,7 @@ impl Endpoint for Tickers {
     }
 
     fn endpoint(&self) -> String {
-        let endpoint = String::from("derivatives/api/v3/tickers/");
-
-        endpoint
+        String::from("derivatives/api/v3/tickers/")
     }
 
     fn is_authenticated(&self) -> bool {
@@ -5,6 +5,7 @@ pub mod error;
 pub mod ignore;
 pub mod params;
 pub mod query;
+pub mod raw;
 
 pub mod futures;
 pub mod spot;
@@ -1,3 +1,6 @@
+use std::fmt::Display;
+
+use serde::Serialize;
 use url::{form_urlencoded, Url};
 /// A structure for query parameters.
 #[derive(Debug, Default, Clone)]
@@ -33,11 +36,21 @@ impl<'a> QueryParams<'a> {
         let mut pairs = url.query_pairs_mut();
         pairs.extend_pairs(self.params.iter());
     }
+}
 
-    pub fn to_string(&self) -> String {
+impl<'a> Display for QueryParams<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut serializer = form_urlencoded::Serializer::new(String::new());
         serializer.extend_pairs(self.params.iter());
 
-        serializer.finish()
+        write!(f, "{}", serializer.finish())
+    }
+}
+
+/// Serializes a unit enum such as an order type to its wire name, for use as a query value.
+pub(crate) fn query_value<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value).expect("Serialization failed") {
+        serde_json::Value::String(value) => value,
+        value => value.to_string(),
     }
 }
@@ -5,13 +5,15 @@ use serde_json::{Map, Value};
 
 use crate::api::endpoint::Endpoint;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub enum OrderType {
     #[default]
     #[serde(rename = "market")]
     Market,
     #[serde(rename = "limit")]
     Limit,
+    #[serde(rename = "iceberg")]
+    Iceberg,
     #[serde(rename = "stop-loss")]
     StopLoss,
     #[serde(rename = "take-profit")]
@@ -55,13 +57,13 @@ pub enum Trigger {
     Last,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TimeInForce {
-    #[serde(rename = "GTC")]
+    #[serde(rename = "GTC", alias = "gtc")]
     Gtc,
-    #[serde(rename = "IOC")]
+    #[serde(rename = "IOC", alias = "ioc")]
     Ioc,
-    #[serde(rename = "GTD")]
+    #[serde(rename = "GTD", alias = "gtd")]
     Gtd,
 }
 
@@ -75,6 +77,8 @@ pub enum TimeInForce {
 pub struct AddOrder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub userref: Option<i32>,
+    #[serde(rename = "cl_ord_id", skip_serializing_if = "Option::is_none")]
+    pub cl_ord_id: Option<String>,
     #[serde(rename = "ordertype")]
     pub order_type: OrderType,
     #[serde(rename = "type")]
@@ -92,7 +96,7 @@ pub struct AddOrder {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub leverage: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "reduce_only", skip_serializing_if = "Option::is_none")]
     pub reduce_only: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -169,7 +173,7 @@ impl Endpoint for AddOrder {
 
     fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
         let serialized_params: serde_json::Value =
-            serde_json::to_value(&self).expect("Serialization failed");
+            serde_json::to_value(self).expect("Serialization failed");
 
         match serialized_params {
             serde_json::Value::Object(params) => {
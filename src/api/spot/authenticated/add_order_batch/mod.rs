@@ -0,0 +1,150 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+use super::add_order::{AddOrder, Descr};
+
+/// Places a collection of orders (minimum of 2 and maximum 15) on a single pair.
+///
+/// Each order reuses the `AddOrder` fields, its `pair`, `deadline` and `validate` are ignored
+/// in favor of the ones of the batch.
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(
+    build_fn(validate = "Self::validate"),
+    setter(strip_option, into),
+    default
+)]
+pub struct AddOrderBatch {
+    pub orders: Vec<AddOrder>,
+    pub pair: String,
+    pub deadline: Option<String>,
+    pub validate_args: Option<bool>,
+}
+
+impl AddOrderBatchBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let orders = self.orders.as_deref().unwrap_or_default();
+
+        if orders.len() < 2 || orders.len() > 15 {
+            return Err("A batch must contain between 2 and 15 orders".to_string());
+        }
+
+        let pair = self.pair.as_deref().unwrap_or_default();
+
+        if pair.is_empty() {
+            return Err("Field pair is required".to_string());
+        }
+
+        if orders
+            .iter()
+            .any(|order| !order.pair.is_empty() && order.pair != pair)
+        {
+            return Err("All the orders of a batch must be on the same pair".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl AddOrderBatch {
+    pub fn builder() -> AddOrderBatchBuilder {
+        AddOrderBatchBuilder::default()
+    }
+}
+
+/// Converts an `AddOrder` into its batch representation.
+///
+/// Decimals are sent as strings and the `close[...]` fields are nested in a `close` object.
+fn batch_entry(order: &AddOrder) -> Value {
+    let serialized_params: serde_json::Value =
+        serde_json::to_value(order).expect("Serialization failed");
+
+    let mut entry = Map::new();
+    let mut close = Map::new();
+
+    if let serde_json::Value::Object(params) = serialized_params {
+        for (key, value) in params {
+            let value = match value {
+                Value::Number(number) if number.is_f64() => Value::String(number.to_string()),
+                value => value,
+            };
+
+            match key.as_str() {
+                "pair" | "deadline" | "validate" => {}
+                "close[ordertype]" => {
+                    close.insert("ordertype".to_string(), value);
+                }
+                "close[price]" => {
+                    close.insert("price".to_string(), value);
+                }
+                "close[price2]" => {
+                    close.insert("price2".to_string(), value);
+                }
+                _ => {
+                    entry.insert(key, value);
+                }
+            }
+        }
+    }
+
+    if !close.is_empty() {
+        entry.insert("close".to_string(), Value::Object(close));
+    }
+
+    Value::Object(entry)
+}
+
+impl Endpoint for AddOrderBatch {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/AddOrderBatch")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let mut params = Map::new();
+
+        params.insert(
+            "orders".to_string(),
+            Value::Array(self.orders.iter().map(batch_entry).collect()),
+        );
+        params.insert("pair".to_string(), Value::String(self.pair.clone()));
+
+        if let Some(deadline) = &self.deadline {
+            params.insert("deadline".to_string(), Value::String(deadline.clone()));
+        }
+
+        if let Some(validate) = self.validate_args {
+            params.insert("validate".to_string(), Value::Bool(validate));
+        }
+
+        Some(("application/json", params))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AddOrderBatchEntry {
+    pub descr: Option<Descr>,
+    pub txid: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AddOrderBatchResult {
+    pub orders: Vec<AddOrderBatchEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AddOrderBatchResp {
+    pub error: Vec<String>,
+    pub result: AddOrderBatchResult,
+}
@@ -0,0 +1,103 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(
+    build_fn(validate = "Self::validate"),
+    setter(strip_option, into),
+    default
+)]
+pub struct AmendOrder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cl_ord_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_qty: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_qty: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<String>,
+}
+
+impl AmendOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match (
+            self.txid.clone().flatten(),
+            self.cl_ord_id.clone().flatten(),
+        ) {
+            (None, None) => return Err("Either txid or cl_ord_id must be set".to_string()),
+            (Some(_), Some(_)) => {
+                return Err("Only one of txid and cl_ord_id can be set".to_string())
+            }
+            _ => {}
+        }
+
+        if let Some(Some(arg)) = self.order_qty {
+            if arg <= 0.0 {
+                return Err("Field order_qty must be positive".to_string());
+            }
+        }
+
+        if let Some(Some(arg)) = self.display_qty {
+            if arg == 0.0 {
+                return Err("Field display_qty cannot be 0".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl AmendOrder {
+    pub fn builder() -> AmendOrderBuilder {
+        AmendOrderBuilder::default()
+    }
+}
+
+impl Endpoint for AmendOrder {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/AmendOrder")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmendOrderResult {
+    pub amend_id: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmendOrderResp {
+    pub error: Vec<String>,
+    pub result: AmendOrderResult,
+}
@@ -0,0 +1,44 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CancelAll {}
+
+impl CancelAll {
+    pub fn builder() -> CancelAllBuilder {
+        CancelAllBuilder::default()
+    }
+}
+
+impl Endpoint for CancelAll {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/CancelAll")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        Some(("application/x-www-form-urlencoded", Map::new()))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelAllResult {
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelAllResp {
+    pub error: Vec<String>,
+    pub result: CancelAllResult,
+}
@@ -0,0 +1,56 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+/// Dead man's switch: cancels all orders once `timeout` seconds have elapsed.
+///
+/// The timer has to be refreshed periodically, sending a `timeout` of 0 disables it.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(into))]
+pub struct CancelAllOrdersAfter {
+    pub timeout: u64,
+}
+
+impl CancelAllOrdersAfter {
+    pub fn builder() -> CancelAllOrdersAfterBuilder {
+        CancelAllOrdersAfterBuilder::default()
+    }
+}
+
+impl Endpoint for CancelAllOrdersAfter {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/CancelAllOrdersAfter")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let mut params = Map::new();
+
+        params.insert("timeout".to_string(), Value::Number(self.timeout.into()));
+
+        Some(("application/x-www-form-urlencoded", params))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOrdersAfterResult {
+    pub current_time: String,
+    pub trigger_time: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelAllOrdersAfterResp {
+    pub error: Vec<String>,
+    pub result: CancelAllOrdersAfterResult,
+}
@@ -0,0 +1,89 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+/// Cancels a particular open order (or set of open orders) by `txid`, `userref` or `cl_ord_id`.
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(
+    build_fn(validate = "Self::validate"),
+    setter(strip_option, into),
+    default
+)]
+pub struct CancelOrder {
+    pub txid: Option<String>,
+    pub userref: Option<i32>,
+    pub cl_ord_id: Option<String>,
+}
+
+impl CancelOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let set = [
+            self.txid.clone().flatten().is_some(),
+            self.userref.flatten().is_some(),
+            self.cl_ord_id.clone().flatten().is_some(),
+        ]
+        .iter()
+        .filter(|is_set| **is_set)
+        .count();
+
+        if set != 1 {
+            return Err("Exactly one of txid, userref and cl_ord_id must be set".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl CancelOrder {
+    pub fn builder() -> CancelOrderBuilder {
+        CancelOrderBuilder::default()
+    }
+}
+
+impl Endpoint for CancelOrder {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/CancelOrder")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let mut params = Map::new();
+
+        // Kraken expects both the txid and the userref in the `txid` field.
+        if let Some(txid) = &self.txid {
+            params.insert("txid".to_string(), Value::String(txid.clone()));
+        }
+
+        if let Some(userref) = self.userref {
+            params.insert("txid".to_string(), Value::Number(userref.into()));
+        }
+
+        if let Some(cl_ord_id) = &self.cl_ord_id {
+            params.insert("cl_ord_id".to_string(), Value::String(cl_ord_id.clone()));
+        }
+
+        Some(("application/x-www-form-urlencoded", params))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelOrderResult {
+    pub count: u32,
+    pub pending: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelOrderResp {
+    pub error: Vec<String>,
+    pub result: CancelOrderResult,
+}
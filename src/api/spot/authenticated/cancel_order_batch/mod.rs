@@ -0,0 +1,98 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+/// Cancels multiple open orders (maximum 50) by `txid`, `userref` or `cl_ord_id`.
+#[derive(Debug, Clone, Builder, Default)]
+#[builder(build_fn(validate = "Self::validate"), setter(into), default)]
+pub struct CancelOrderBatch {
+    pub txids: Vec<String>,
+    pub userrefs: Vec<i32>,
+    pub cl_ord_ids: Vec<String>,
+}
+
+impl CancelOrderBatchBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let count = self.txids.as_ref().map_or(0, Vec::len)
+            + self.userrefs.as_ref().map_or(0, Vec::len)
+            + self.cl_ord_ids.as_ref().map_or(0, Vec::len);
+
+        if count == 0 {
+            return Err("At least one order to cancel must be provided".to_string());
+        }
+
+        if count > 50 {
+            return Err("A batch cannot cancel more than 50 orders".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl CancelOrderBatch {
+    pub fn builder() -> CancelOrderBatchBuilder {
+        CancelOrderBatchBuilder::default()
+    }
+}
+
+impl Endpoint for CancelOrderBatch {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/CancelOrderBatch")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let mut params = Map::new();
+
+        // Kraken expects both the txids and the userrefs in the `orders` field.
+        let orders: Vec<Value> = self
+            .txids
+            .iter()
+            .map(|txid| Value::String(txid.clone()))
+            .chain(
+                self.userrefs
+                    .iter()
+                    .map(|userref| Value::Number((*userref).into())),
+            )
+            .collect();
+
+        if !orders.is_empty() {
+            params.insert("orders".to_string(), Value::Array(orders));
+        }
+
+        if !self.cl_ord_ids.is_empty() {
+            params.insert(
+                "cl_ord_ids".to_string(),
+                Value::Array(
+                    self.cl_ord_ids
+                        .iter()
+                        .map(|cl_ord_id| Value::String(cl_ord_id.clone()))
+                        .collect(),
+                ),
+            );
+        }
+
+        Some(("application/json", params))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelOrderBatchResult {
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelOrderBatchResp {
+    pub error: Vec<String>,
+    pub result: CancelOrderBatchResult,
+}
@@ -0,0 +1,52 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+/// Allocates funds to an Earn strategy.
+///
+/// The allocation is asynchronous, its progress can be followed with `AllocateStatus`.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct Allocate {
+    pub amount: String,
+    pub strategy_id: String,
+}
+
+impl Allocate {
+    pub fn builder() -> AllocateBuilder {
+        AllocateBuilder::default()
+    }
+}
+
+impl Endpoint for Allocate {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/Earn/Allocate")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => Some(("application/json", params)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AllocateResp {
+    pub error: Vec<String>,
+    pub result: bool,
+}
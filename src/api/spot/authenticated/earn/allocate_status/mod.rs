@@ -0,0 +1,53 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct AllocateStatus {
+    pub strategy_id: String,
+}
+
+impl AllocateStatus {
+    pub fn builder() -> AllocateStatusBuilder {
+        AllocateStatusBuilder::default()
+    }
+}
+
+impl Endpoint for AllocateStatus {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/Earn/AllocateStatus")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => Some(("application/json", params)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OperationStatus {
+    pub pending: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AllocateStatusResp {
+    pub error: Vec<String>,
+    pub result: OperationStatus,
+}
@@ -0,0 +1,50 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+use super::allocate_status::OperationStatus;
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct DeallocateStatus {
+    pub strategy_id: String,
+}
+
+impl DeallocateStatus {
+    pub fn builder() -> DeallocateStatusBuilder {
+        DeallocateStatusBuilder::default()
+    }
+}
+
+impl Endpoint for DeallocateStatus {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/Earn/DeallocateStatus")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => Some(("application/json", params)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DeallocateStatusResp {
+    pub error: Vec<String>,
+    pub result: OperationStatus,
+}
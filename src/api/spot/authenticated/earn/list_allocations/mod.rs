@@ -0,0 +1,108 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::deserialize_number_from_string;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct ListAllocations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ascending: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub converted_asset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hide_zero_allocations: Option<bool>,
+}
+
+impl ListAllocations {
+    pub fn builder() -> ListAllocationsBuilder {
+        ListAllocationsBuilder::default()
+    }
+}
+
+impl Endpoint for ListAllocations {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/Earn/Allocations")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => Some(("application/json", params)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EarnAmount {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub native: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub converted: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AllocationState {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub native: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub converted: f64,
+    pub allocation_count: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmountAllocated {
+    pub bonding: Option<AllocationState>,
+    pub exit_queue: Option<AllocationState>,
+    pub pending: Option<EarnAmount>,
+    pub unbonding: Option<AllocationState>,
+    pub total: EarnAmount,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Payout {
+    pub accumulated_reward: EarnAmount,
+    pub estimated_reward: EarnAmount,
+    pub period_start: String,
+    pub period_end: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Allocation {
+    pub strategy_id: String,
+    pub native_asset: String,
+    pub amount_allocated: AmountAllocated,
+    pub total_rewarded: EarnAmount,
+    pub payout: Option<Payout>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListAllocationsResult {
+    pub converted_asset: String,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub total_allocated: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub total_rewarded: f64,
+    pub next_cursor: Option<String>,
+    pub items: Vec<Allocation>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListAllocationsResp {
+    pub error: Vec<String>,
+    pub result: ListAllocationsResult,
+}
@@ -0,0 +1,144 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::{deserialize_number_from_string, deserialize_option_number_from_string};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LockType {
+    Flex,
+    Bonded,
+    Timed,
+    Instant,
+}
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct ListStrategies {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ascending: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_type: Option<Vec<LockType>>,
+}
+
+impl ListStrategies {
+    pub fn builder() -> ListStrategiesBuilder {
+        ListStrategiesBuilder::default()
+    }
+}
+
+impl Endpoint for ListStrategies {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/Earn/Strategies")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => Some(("application/json", params)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StrategyLockType {
+    Flex {
+        payout_frequency: Option<u64>,
+    },
+    Bonded {
+        payout_frequency: Option<u64>,
+        bonding_period: Option<u64>,
+        bonding_period_variable: Option<bool>,
+        bonding_rewards: Option<bool>,
+        exit_queue_period: Option<u64>,
+        unbonding_period: Option<u64>,
+        unbonding_period_variable: Option<bool>,
+        unbonding_rewards: Option<bool>,
+    },
+    Timed {
+        payout_frequency: Option<u64>,
+        duration: Option<u64>,
+    },
+    Instant {
+        payout_frequency: Option<u64>,
+    },
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AprEstimate {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub low: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub high: f64,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AutoCompound {
+    Enabled,
+    Disabled,
+    Optional { default: bool },
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum YieldSource {
+    Staking,
+    OffChain,
+    OptInRewards,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Strategy {
+    pub id: String,
+    pub asset: String,
+    pub lock_type: StrategyLockType,
+    pub apr_estimate: Option<AprEstimate>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub user_min_allocation: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number_from_string")]
+    pub user_cap: Option<f64>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub allocation_fee: f64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub deallocation_fee: f64,
+    pub auto_compound: AutoCompound,
+    pub yield_source: YieldSource,
+    pub can_allocate: bool,
+    pub can_deallocate: bool,
+    #[serde(default)]
+    pub allocation_restriction_info: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListStrategiesResult {
+    pub items: Vec<Strategy>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ListStrategiesResp {
+    pub error: Vec<String>,
+    pub result: ListStrategiesResult,
+}
@@ -0,0 +1,6 @@
+pub mod list_strategies;
+pub mod list_allocations;
+pub mod allocate;
+pub mod deallocate;
+pub mod allocate_status;
+pub mod deallocate_status;
\ No newline at end of file
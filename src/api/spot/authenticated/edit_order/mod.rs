@@ -0,0 +1,119 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(
+    build_fn(validate = "Self::validate"),
+    setter(strip_option, into),
+    default
+)]
+pub struct EditOrder {
+    pub txid: String,
+    pub pair: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub userref: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<f64>,
+    #[serde(rename = "displayvol", skip_serializing_if = "Option::is_none")]
+    pub display_vol: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price2: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oflags: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_response: Option<bool>,
+    #[serde(rename = "validate", skip_serializing_if = "Option::is_none")]
+    pub validate_args: Option<bool>,
+}
+
+impl EditOrderBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if self.txid.as_deref().unwrap_or_default().is_empty() {
+            return Err("Field txid is required".to_string());
+        }
+
+        if self.pair.as_deref().unwrap_or_default().is_empty() {
+            return Err("Field pair is required".to_string());
+        }
+
+        if let Some(Some(arg)) = self.display_vol {
+            if arg == 0.0 {
+                return Err("Field display_vol cannot be 0".to_string());
+            }
+        }
+
+        if let Some(Some(arg)) = self.volume {
+            if arg <= 0.0 {
+                return Err("Field volume must be positive".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl EditOrder {
+    pub fn builder() -> EditOrderBuilder {
+        EditOrderBuilder::default()
+    }
+}
+
+impl Endpoint for EditOrder {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/EditOrder")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EditOrderDescr {
+    pub order: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EditOrderResult {
+    pub descr: Option<EditOrderDescr>,
+    pub txid: Option<String>,
+    pub newuserref: Option<i32>,
+    pub olduserref: Option<i32>,
+    pub orders_cancelled: Option<u32>,
+    pub originaltxid: Option<String>,
+    pub status: Option<String>,
+    pub volume: Option<String>,
+    pub price: Option<String>,
+    pub price2: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EditOrderResp {
+    pub error: Vec<String>,
+    pub result: EditOrderResult,
+}
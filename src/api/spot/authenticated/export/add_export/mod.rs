@@ -0,0 +1,84 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum ExportReportType {
+    #[default]
+    #[serde(rename = "trades")]
+    Trades,
+    #[serde(rename = "ledgers")]
+    Ledgers,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    #[serde(rename = "CSV")]
+    Csv,
+    #[serde(rename = "TSV")]
+    Tsv,
+}
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct AddExport {
+    pub report: ExportReportType,
+    pub format: ExportFormat,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub fields: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub starttm: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub endtm: Option<u64>,
+}
+
+impl AddExport {
+    pub fn builder() -> AddExportBuilder {
+        AddExportBuilder::default()
+    }
+}
+
+impl Endpoint for AddExport {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/AddExport")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AddExportResult {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AddExportResp {
+    pub error: Vec<String>,
+    pub result: AddExportResult,
+}
@@ -0,0 +1,79 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+use super::add_export::{ExportFormat, ExportReportType};
+
+#[derive(Debug, Clone, Copy, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct ExportStatus {
+    pub report: ExportReportType,
+}
+
+impl ExportStatus {
+    pub fn builder() -> ExportStatusBuilder {
+        ExportStatusBuilder::default()
+    }
+}
+
+impl Endpoint for ExportStatus {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/ExportStatus")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReportStatus {
+    Queued,
+    Processing,
+    Processed,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Report {
+    pub id: String,
+    pub descr: String,
+    pub format: ExportFormat,
+    pub report: ExportReportType,
+    pub subtype: Option<String>,
+    pub status: ReportStatus,
+    pub flags: Option<String>,
+    pub fields: String,
+    pub createdtm: String,
+    pub expiretm: Option<String>,
+    pub starttm: Option<String>,
+    pub completedtm: Option<String>,
+    pub datastarttm: Option<String>,
+    pub dataendtm: Option<String>,
+    pub aclass: Option<String>,
+    pub asset: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExportStatusResp {
+    pub error: Vec<String>,
+    pub result: Vec<Report>,
+}
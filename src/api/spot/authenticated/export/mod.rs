@@ -0,0 +1,5 @@
+pub mod add_export;
+pub mod export_status;
+pub mod retrieve_export;
+pub mod remove_export;
+pub mod report;
\ No newline at end of file
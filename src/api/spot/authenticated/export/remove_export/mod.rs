@@ -0,0 +1,67 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Copy, Serialize, Default, PartialEq, Eq)]
+pub enum RemoveExportType {
+    #[default]
+    #[serde(rename = "delete")]
+    Delete,
+    #[serde(rename = "cancel")]
+    Cancel,
+}
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct RemoveExport {
+    pub id: String,
+    #[builder(default)]
+    pub r#type: RemoveExportType,
+}
+
+impl RemoveExport {
+    pub fn builder() -> RemoveExportBuilder {
+        RemoveExportBuilder::default()
+    }
+}
+
+impl Endpoint for RemoveExport {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/RemoveExport")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RemoveExportResult {
+    pub delete: Option<bool>,
+    pub cancel: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RemoveExportResp {
+    pub error: Vec<String>,
+    pub result: RemoveExportResult,
+}
@@ -0,0 +1,288 @@
+use std::{
+    error::Error,
+    io::{Cursor, Read},
+    num::NonZeroU32,
+    thread,
+    time::Duration,
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use thiserror::Error;
+
+use crate::api::{
+    client::{AsyncClient, Client},
+    error::ApiError,
+    query::{AsyncQuery, Query},
+    raw::raw,
+    spot::authenticated::add_order::OrderSide,
+};
+
+use super::{
+    add_export::{AddExport, AddExportResp, ExportFormat, ExportReportType},
+    export_status::{ExportStatus, ExportStatusResp, ReportStatus},
+    remove_export::{RemoveExport, RemoveExportResp, RemoveExportType},
+    retrieve_export::RetrieveExport,
+};
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("Failed to read the export archive: {}", source)]
+    Zip {
+        #[from]
+        source: zip::result::ZipError,
+    },
+
+    #[error("Failed to read the export archive: {}", source)]
+    Io {
+        #[from]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse the export report: {}", source)]
+    Csv {
+        #[from]
+        source: csv::Error,
+    },
+
+    /// The archive does not contain any report.
+    #[error("The export archive is empty")]
+    EmptyArchive,
+}
+
+#[derive(Debug, Error)]
+pub enum ExportError<E>
+where
+    E: Error + 'static,
+{
+    #[error("{}", source)]
+    Api {
+        #[from]
+        source: ApiError<E>,
+    },
+
+    #[error("{}", source)]
+    Parse {
+        #[from]
+        source: ParseError,
+    },
+
+    /// The requested report disappeared from the export status list (deleted or expired).
+    #[error("Export report {} not found", id)]
+    NotFound { id: String },
+
+    /// The report was still not processed after `polls` status checks.
+    #[error("Export report {} not processed after {} polls", id, polls)]
+    Timeout { id: String, polls: u32 },
+
+    /// `RetrieveExport` answered with a JSON error instead of the archive.
+    #[error("Kraken failed to retrieve the export: {}", errors.join(", "))]
+    Kraken { errors: Vec<String> },
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TradeRecord {
+    pub txid: String,
+    pub ordertxid: String,
+    pub pair: String,
+    pub time: String,
+    #[serde(rename = "type")]
+    pub side: OrderSide,
+    pub ordertype: String,
+    pub price: f64,
+    pub cost: f64,
+    pub fee: f64,
+    pub vol: f64,
+    pub margin: f64,
+    pub misc: Option<String>,
+    pub ledgers: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LedgerRecord {
+    pub txid: String,
+    pub refid: String,
+    pub time: String,
+    pub r#type: String,
+    pub subtype: Option<String>,
+    pub aclass: String,
+    pub asset: String,
+    pub wallet: Option<String>,
+    pub amount: f64,
+    pub fee: f64,
+    pub balance: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ExportRecords {
+    Trades(Vec<TradeRecord>),
+    Ledgers(Vec<LedgerRecord>),
+}
+
+fn parse_records<T: DeserializeOwned>(
+    data: &[u8],
+    format: ExportFormat,
+) -> Result<Vec<T>, ParseError> {
+    let delimiter = match format {
+        ExportFormat::Csv => b',',
+        ExportFormat::Tsv => b'\t',
+    };
+
+    csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .from_reader(data)
+        .deserialize()
+        .map(|record| record.map_err(ParseError::from))
+        .collect()
+}
+
+/// Parses the ZIP archive returned by `RetrieveExport` into typed records.
+pub fn parse_export(
+    archive: &[u8],
+    report: ExportReportType,
+    format: ExportFormat,
+) -> Result<ExportRecords, ParseError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(archive))?;
+
+    if archive.is_empty() {
+        return Err(ParseError::EmptyArchive);
+    }
+
+    let mut data = Vec::new();
+    archive.by_index(0)?.read_to_end(&mut data)?;
+
+    match report {
+        ExportReportType::Trades => parse_records(&data, format).map(ExportRecords::Trades),
+        ExportReportType::Ledgers => parse_records(&data, format).map(ExportRecords::Ledgers),
+    }
+}
+
+/// `RetrieveExport` may answer with a JSON error body and a success status, which would
+/// otherwise surface as an invalid archive.
+fn check_archive<E>(archive: &[u8]) -> Result<(), ExportError<E>>
+where
+    E: Error + 'static,
+{
+    if archive.starts_with(b"PK") {
+        return Ok(());
+    }
+
+    #[derive(Deserialize)]
+    struct ErrorResp {
+        error: Vec<String>,
+    }
+
+    match serde_json::from_slice::<ErrorResp>(archive) {
+        Ok(rsp) if !rsp.error.is_empty() => Err(ExportError::Kraken { errors: rsp.error }),
+        _ => Ok(()),
+    }
+}
+
+fn remove_export(id: &str, r#type: RemoveExportType) -> RemoveExport {
+    RemoveExport {
+        id: id.to_string(),
+        r#type,
+    }
+}
+
+/// Checks whether the report is ready to be retrieved.
+fn is_processed<E>(status: ExportStatusResp, id: &str) -> Result<bool, ExportError<E>>
+where
+    E: Error + 'static,
+{
+    status
+        .result
+        .into_iter()
+        .find(|report| report.id == id)
+        .map(|report| report.status == ReportStatus::Processed)
+        .ok_or_else(|| ExportError::NotFound { id: id.to_string() })
+}
+
+/// Requests an export report, waits for it to be processed and returns its parsed content.
+///
+/// The status is checked at most `max_polls` times, `poll_interval` apart. The report is deleted
+/// from Kraken once downloaded and cancelled on timeout, failures to remove it are ignored.
+pub fn export_report<C>(
+    client: &C,
+    export: &AddExport,
+    poll_interval: Duration,
+    max_polls: NonZeroU32,
+) -> Result<ExportRecords, ExportError<C::Error>>
+where
+    C: for<'a> Client<'a>,
+{
+    let rsp: AddExportResp = export.query(client)?;
+    let id = rsp.result.id;
+
+    let status = ExportStatus {
+        report: export.report,
+    };
+
+    let mut polls = 0;
+    while !is_processed(status.query(client)?, &id)? {
+        polls += 1;
+        if polls >= max_polls.get() {
+            let _: Result<RemoveExportResp, _> =
+                remove_export(&id, RemoveExportType::Cancel).query(client);
+
+            return Err(ExportError::Timeout { id, polls });
+        }
+
+        thread::sleep(poll_interval);
+    }
+
+    let archive = raw(RetrieveExport { id: id.clone() }).query(client);
+    let _: Result<RemoveExportResp, _> = remove_export(&id, RemoveExportType::Delete).query(client);
+
+    let archive = archive?;
+    check_archive(&archive)?;
+
+    Ok(parse_export(&archive, export.report, export.format)?)
+}
+
+/// Requests an export report, waits for it to be processed and returns its parsed content asynchronously.
+///
+/// The status is checked at most `max_polls` times, `poll_interval` apart. The report is deleted
+/// from Kraken once downloaded and cancelled on timeout, failures to remove it are ignored.
+pub async fn export_report_async<C>(
+    client: &C,
+    export: &AddExport,
+    poll_interval: Duration,
+    max_polls: NonZeroU32,
+) -> Result<ExportRecords, ExportError<C::Error>>
+where
+    C: for<'a> AsyncClient<'a> + Sync,
+    C::Error: 'static,
+{
+    let rsp: AddExportResp = export.query_async(client).await?;
+    let id = rsp.result.id;
+
+    let status = ExportStatus {
+        report: export.report,
+    };
+
+    let mut polls = 0;
+    while !is_processed(status.query_async(client).await?, &id)? {
+        polls += 1;
+        if polls >= max_polls.get() {
+            let _: Result<RemoveExportResp, _> = remove_export(&id, RemoveExportType::Cancel)
+                .query_async(client)
+                .await;
+
+            return Err(ExportError::Timeout { id, polls });
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+
+    let archive = raw(RetrieveExport { id: id.clone() })
+        .query_async(client)
+        .await;
+    let _: Result<RemoveExportResp, _> = remove_export(&id, RemoveExportType::Delete)
+        .query_async(client)
+        .await;
+
+    let archive = archive?;
+    check_archive(&archive)?;
+
+    Ok(parse_export(&archive, export.report, export.format)?)
+}
@@ -0,0 +1,42 @@
+use derive_builder::Builder;
+use http::Method;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+/// Retrieves a processed export report.
+///
+/// The response is a binary ZIP archive, it has to be queried through `api::raw::raw`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct RetrieveExport {
+    pub id: String,
+}
+
+impl RetrieveExport {
+    pub fn builder() -> RetrieveExportBuilder {
+        RetrieveExportBuilder::default()
+    }
+}
+
+impl Endpoint for RetrieveExport {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/RetrieveExport")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let mut params = Map::new();
+
+        params.insert("id".to_string(), Value::String(self.id.clone()));
+
+        Some(("application/x-www-form-urlencoded", params))
+    }
+}
@@ -0,0 +1,66 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct DepositAddresses {
+    pub asset: String,
+    pub method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub new: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub amount: Option<f64>,
+}
+
+impl DepositAddresses {
+    pub fn builder() -> DepositAddressesBuilder {
+        DepositAddressesBuilder::default()
+    }
+}
+
+impl Endpoint for DepositAddresses {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/DepositAddresses")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DepositAddress {
+    pub address: String,
+    pub expiretm: String,
+    pub new: Option<bool>,
+    pub memo: Option<String>,
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DepositAddressesResp {
+    pub error: Vec<String>,
+    pub result: Vec<DepositAddress>,
+}
@@ -0,0 +1,73 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct DepositMethods {
+    pub asset: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub aclass: Option<String>,
+}
+
+impl DepositMethods {
+    pub fn builder() -> DepositMethodsBuilder {
+        DepositMethodsBuilder::default()
+    }
+}
+
+impl Endpoint for DepositMethods {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/DepositMethods")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Kraken returns `false` when there is no deposit limit.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum DepositLimit {
+    Unlimited(bool),
+    Amount(String),
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DepositMethod {
+    pub method: String,
+    pub limit: DepositLimit,
+    pub fee: Option<String>,
+    #[serde(rename = "address-setup-fee")]
+    pub address_setup_fee: Option<String>,
+    #[serde(rename = "gen-address")]
+    pub gen_address: Option<bool>,
+    pub minimum: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DepositMethodsResp {
+    pub error: Vec<String>,
+    pub result: Vec<DepositMethod>,
+}
@@ -0,0 +1,9 @@
+pub mod deposit_methods;
+pub mod deposit_addresses;
+pub mod deposit_status;
+pub mod withdraw_methods;
+pub mod withdraw_addresses;
+pub mod withdraw_info;
+pub mod withdraw;
+pub mod withdraw_status;
+pub mod withdraw_cancel;
\ No newline at end of file
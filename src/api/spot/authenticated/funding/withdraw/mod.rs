@@ -0,0 +1,63 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct Withdraw {
+    pub asset: String,
+    pub key: String,
+    pub amount: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(strip_option), default)]
+    pub max_fee: Option<f64>,
+}
+
+impl Withdraw {
+    pub fn builder() -> WithdrawBuilder {
+        WithdrawBuilder::default()
+    }
+}
+
+impl Endpoint for Withdraw {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/Withdraw")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawRef {
+    pub refid: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawResp {
+    pub error: Vec<String>,
+    pub result: WithdrawRef,
+}
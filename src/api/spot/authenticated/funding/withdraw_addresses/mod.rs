@@ -0,0 +1,70 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct WithdrawAddresses {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aclass: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified: Option<bool>,
+}
+
+impl WithdrawAddresses {
+    pub fn builder() -> WithdrawAddressesBuilder {
+        WithdrawAddressesBuilder::default()
+    }
+}
+
+impl Endpoint for WithdrawAddresses {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/WithdrawAddresses")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawAddress {
+    pub address: String,
+    pub asset: String,
+    pub method: String,
+    pub key: String,
+    pub tag: Option<String>,
+    pub memo: Option<String>,
+    pub verified: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawAddressesResp {
+    pub error: Vec<String>,
+    pub result: Vec<WithdrawAddress>,
+}
@@ -0,0 +1,51 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct WithdrawCancel {
+    pub asset: String,
+    pub refid: String,
+}
+
+impl WithdrawCancel {
+    pub fn builder() -> WithdrawCancelBuilder {
+        WithdrawCancelBuilder::default()
+    }
+}
+
+impl Endpoint for WithdrawCancel {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/WithdrawCancel")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawCancelResp {
+    pub error: Vec<String>,
+    pub result: bool,
+}
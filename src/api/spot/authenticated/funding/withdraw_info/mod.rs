@@ -0,0 +1,60 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(setter(into))]
+pub struct WithdrawInfo {
+    pub asset: String,
+    pub key: String,
+    pub amount: f64,
+}
+
+impl WithdrawInfo {
+    pub fn builder() -> WithdrawInfoBuilder {
+        WithdrawInfoBuilder::default()
+    }
+}
+
+impl Endpoint for WithdrawInfo {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/WithdrawInfo")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawInfoResult {
+    pub method: String,
+    pub limit: String,
+    pub amount: String,
+    pub fee: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawInfoResp {
+    pub error: Vec<String>,
+    pub result: WithdrawInfoResult,
+}
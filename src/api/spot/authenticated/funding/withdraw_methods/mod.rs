@@ -0,0 +1,63 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct WithdrawMethods {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aclass: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+impl WithdrawMethods {
+    pub fn builder() -> WithdrawMethodsBuilder {
+        WithdrawMethodsBuilder::default()
+    }
+}
+
+impl Endpoint for WithdrawMethods {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/WithdrawMethods")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawMethod {
+    pub asset: String,
+    pub method: String,
+    pub network: Option<String>,
+    pub minimum: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawMethodsResp {
+    pub error: Vec<String>,
+    pub result: Vec<WithdrawMethod>,
+}
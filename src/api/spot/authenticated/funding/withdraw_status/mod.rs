@@ -0,0 +1,96 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder, Serialize, Default)]
+#[builder(setter(strip_option, into), default)]
+pub struct WithdrawStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aclass: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+}
+
+impl WithdrawStatus {
+    pub fn builder() -> WithdrawStatusBuilder {
+        WithdrawStatusBuilder::default()
+    }
+}
+
+impl Endpoint for WithdrawStatus {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/WithdrawStatus")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => {
+                Some(("application/x-www-form-urlencoded", params))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub enum TransferStatus {
+    Initial,
+    Pending,
+    Settled,
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum StatusProp {
+    CancelPending,
+    Canceled,
+    CancelDenied,
+    Return,
+    Onhold,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct FundingTransaction {
+    pub method: String,
+    pub aclass: Option<String>,
+    pub asset: String,
+    pub refid: String,
+    pub txid: Option<String>,
+    pub info: Option<String>,
+    pub amount: String,
+    pub fee: Option<String>,
+    pub time: u64,
+    pub status: TransferStatus,
+    #[serde(rename = "status-prop")]
+    pub status_prop: Option<StatusProp>,
+    pub key: Option<String>,
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WithdrawStatusResp {
+    pub error: Vec<String>,
+    pub result: Vec<FundingTransaction>,
+}
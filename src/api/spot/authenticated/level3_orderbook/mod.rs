@@ -0,0 +1,82 @@
+use derive_builder::Builder;
+use http::Method;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+/// Returns the individual resting orders (L3) of a book, unlike the aggregated `OrderBook`.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(build_fn(validate = "Self::validate"), setter(strip_option, into))]
+pub struct Level3OrderBook {
+    pub pair: String,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<u32>,
+}
+
+impl Level3OrderBookBuilder {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(Some(depth)) = self.depth {
+            if ![0, 10, 100, 1000].contains(&depth) {
+                return Err("Field depth must be one of 0, 10, 100 or 1000".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Level3OrderBook {
+    pub fn builder() -> Level3OrderBookBuilder {
+        Level3OrderBookBuilder::default()
+    }
+}
+
+impl Endpoint for Level3OrderBook {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/Level3")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let serialized_params: serde_json::Value =
+            serde_json::to_value(self).expect("Serialization failed");
+
+        match serialized_params {
+            serde_json::Value::Object(params) => Some(("application/json", params)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Level3Order {
+    pub order_id: String,
+    pub price: Decimal,
+    #[serde(rename = "qty")]
+    pub quantity: Decimal,
+    /// Time the order was placed at, in nanoseconds.
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Level3OrderBookW {
+    pub pair: String,
+    pub asks: Vec<Level3Order>,
+    pub bids: Vec<Level3Order>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Level3OrderBookResp {
+    pub error: Vec<String>,
+    pub result: Level3OrderBookW,
+}
@@ -1,3 +1,16 @@
 pub mod account;
+pub mod earn;
+pub mod export;
+pub mod funding;
+pub mod subaccount;
+pub mod level3_orderbook;
+pub mod websockets;
 pub mod wallet_transfer;
 pub mod add_order;
+pub mod add_order_batch;
+pub mod edit_order;
+pub mod amend_order;
+pub mod cancel_order;
+pub mod cancel_order_batch;
+pub mod cancel_all;
+pub mod cancel_all_orders_after;
\ No newline at end of file
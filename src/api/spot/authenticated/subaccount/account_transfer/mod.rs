@@ -0,0 +1,69 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+/// Transfers funds between the master account and its subaccounts.
+///
+/// `from` and `to` are the IIBAN of the source and destination accounts.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct AccountTransfer {
+    pub asset: String,
+    pub amount: f64,
+    pub from: String,
+    pub to: String,
+}
+
+impl AccountTransfer {
+    pub fn builder() -> AccountTransferBuilder {
+        AccountTransferBuilder::default()
+    }
+}
+
+impl Endpoint for AccountTransfer {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/AccountTransfer")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let mut params = Map::new();
+
+        params.insert("asset".to_string(), Value::String(self.asset.clone()));
+        params.insert("amount".to_string(), Value::String(self.amount.to_string()));
+        params.insert("from".to_string(), Value::String(self.from.clone()));
+        params.insert("to".to_string(), Value::String(self.to.clone()));
+
+        Some(("application/x-www-form-urlencoded", params))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountTransferStatus {
+    Pending,
+    Complete,
+    Failed,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AccountTransferRef {
+    pub transfer_id: String,
+    pub status: AccountTransferStatus,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AccountTransferResp {
+    pub error: Vec<String>,
+    pub result: AccountTransferRef,
+}
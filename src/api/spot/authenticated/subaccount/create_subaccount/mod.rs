@@ -0,0 +1,48 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct CreateSubaccount {
+    pub username: String,
+    pub email: String,
+}
+
+impl CreateSubaccount {
+    pub fn builder() -> CreateSubaccountBuilder {
+        CreateSubaccountBuilder::default()
+    }
+}
+
+impl Endpoint for CreateSubaccount {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/CreateSubaccount")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        let mut params = Map::new();
+
+        params.insert("username".to_string(), Value::String(self.username.clone()));
+        params.insert("email".to_string(), Value::String(self.email.clone()));
+
+        Some(("application/x-www-form-urlencoded", params))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateSubaccountResp {
+    pub error: Vec<String>,
+    pub result: bool,
+}
@@ -0,0 +1,2 @@
+pub mod create_subaccount;
+pub mod account_transfer;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct GetWebSocketsToken {}
+
+impl GetWebSocketsToken {
+    pub fn builder() -> GetWebSocketsTokenBuilder {
+        GetWebSocketsTokenBuilder::default()
+    }
+}
+
+impl Endpoint for GetWebSocketsToken {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("/0/private/GetWebSocketsToken")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Map<String, Value>)> {
+        Some(("application/x-www-form-urlencoded", Map::new()))
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebSocketsToken {
+    pub token: String,
+    /// Validity of the token in seconds.
+    pub expires: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct GetWebSocketsTokenResp {
+    pub error: Vec<String>,
+    pub result: WebSocketsToken,
+}
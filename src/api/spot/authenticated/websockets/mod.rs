@@ -0,0 +1,2 @@
+pub mod get_websockets_token;
+pub mod token_manager;
\ No newline at end of file
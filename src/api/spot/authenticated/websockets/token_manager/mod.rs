@@ -0,0 +1,106 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Mutex;
+
+use crate::{
+    api::{client::AsyncClient, error::ApiError, query::AsyncQuery},
+    kraken::AsyncKraken,
+};
+
+use super::get_websockets_token::{GetWebSocketsToken, GetWebSocketsTokenResp};
+
+/// How long before its expiry a token is considered stale by default.
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+    valid_until: Instant,
+}
+
+/// Caches the token returned by `GetWebSocketsToken` and fetches a new one before it expires.
+///
+/// Kraken tokens have to be used to open a connection within their validity (15 minutes),
+/// a token stays valid for as long as the connection using it remains open.
+#[derive(Debug)]
+pub struct WebSocketsTokenManager<C = AsyncKraken> {
+    client: Arc<C>,
+    refresh_margin: Duration,
+    cached: Mutex<Option<CachedToken>>,
+}
+
+impl<C> WebSocketsTokenManager<C>
+where
+    C: for<'a> AsyncClient<'a> + Sync,
+{
+    pub fn new(client: Arc<C>) -> Self {
+        Self::with_refresh_margin(client, DEFAULT_REFRESH_MARGIN)
+    }
+
+    pub fn with_refresh_margin(client: Arc<C>, refresh_margin: Duration) -> Self {
+        Self {
+            client,
+            refresh_margin,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Returns the cached token, or a new one if it is missing or about to expire.
+    pub async fn token(&self) -> Result<String, ApiError<C::Error>> {
+        let mut cached = self.cached.lock().await;
+
+        if let Some(token) = cached.as_ref() {
+            if Instant::now() + self.refresh_margin < token.valid_until {
+                return Ok(token.token.clone());
+            }
+        }
+
+        let token = self.fetch().await?;
+        let value = token.token.clone();
+        *cached = Some(token);
+
+        Ok(value)
+    }
+
+    /// Fetches a new token regardless of the validity of the cached one.
+    pub async fn refresh(&self) -> Result<String, ApiError<C::Error>> {
+        let mut cached = self.cached.lock().await;
+
+        let token = self.fetch().await?;
+        let value = token.token.clone();
+        *cached = Some(token);
+
+        Ok(value)
+    }
+
+    /// Drops the cached token, the next call to `token` will fetch a new one.
+    pub async fn invalidate(&self) {
+        *self.cached.lock().await = None;
+    }
+
+    /// Returns the remaining validity of the cached token, if any.
+    pub async fn expires_in(&self) -> Option<Duration> {
+        self.cached
+            .lock()
+            .await
+            .as_ref()
+            .map(|token| token.valid_until.saturating_duration_since(Instant::now()))
+    }
+
+    async fn fetch(&self) -> Result<CachedToken, ApiError<C::Error>> {
+        // The validity is computed from the time of the request to stay on the safe side.
+        let requested_at = Instant::now();
+
+        let rsp: GetWebSocketsTokenResp = GetWebSocketsToken {}
+            .query_async(self.client.as_ref())
+            .await?;
+
+        Ok(CachedToken {
+            token: rsp.result.token,
+            valid_until: requested_at + Duration::from_secs(rsp.result.expires),
+        })
+    }
+}
@@ -41,7 +41,7 @@ impl Endpoint for AssetPairs {
         "/0/public/AssetPairs".to_owned()
     }
 
-    fn parameters(&self) -> Option<QueryParams> {
+    fn parameters(&self) -> Option<QueryParams<'_>> {
         let mut params = QueryParams::default();
 
         if let Some(pair) = &self.pair {
@@ -28,7 +28,7 @@ impl Endpoint for OrderBook {
         "/0/public/Depth".to_owned()
     }
 
-    fn parameters(&self) -> Option<QueryParams> {
+    fn parameters(&self) -> Option<QueryParams<'_>> {
         let mut params = QueryParams::default();
         params.push("pair", self.pair.to_string());
         params.push("count", self.count.to_string());
@@ -1,9 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, num::ParseFloatError};
 
 use crate::api::{endpoint::Endpoint, params::QueryParams};
 use derive_builder::Builder;
 use http::Method;
 use serde::Deserialize;
+use thiserror::Error;
 
 #[derive(Debug, Clone, Default, Builder)]
 #[builder(setter(strip_option, into), default)]
@@ -26,7 +27,7 @@ impl Endpoint for Ticker {
         "/0/public/Ticker".to_owned()
     }
 
-    fn parameters(&self) -> Option<QueryParams> {
+    fn parameters(&self) -> Option<QueryParams<'_>> {
         let mut params = QueryParams::default();
 
         if let Some(pair) = &self.pair {
@@ -63,3 +64,133 @@ pub struct AssetTicker {
 pub struct TickerResp {
     pub result: HashMap<String, AssetTicker>,
 }
+
+impl TickerResp {
+    /// Decodes the tickers of every pair into their named representation.
+    pub fn decode(&self) -> Result<HashMap<String, DecodedTicker>, TickerDecodeError> {
+        self.result
+            .iter()
+            .map(|(pair, ticker)| Ok((pair.clone(), ticker.decode()?)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TickerDecodeError {
+    /// A positional value is missing from one of the arrays.
+    #[error("Missing value {} of field {}", index, field)]
+    Missing { field: &'static str, index: usize },
+
+    /// A value could not be parsed as a number.
+    #[error("Invalid number for field {}: {}", field, source)]
+    InvalidNumber {
+        field: &'static str,
+        source: ParseFloatError,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookTop {
+    pub price: f64,
+    pub whole_lot_volume: f64,
+    pub lot_volume: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LastTrade {
+    pub price: f64,
+    pub lot_volume: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DailyValue<T> {
+    pub today: T,
+    pub last_24_hours: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecodedTicker {
+    pub ask: BookTop,
+    pub bid: BookTop,
+    pub last_trade: LastTrade,
+    pub volume: DailyValue<f64>,
+    pub vwap: DailyValue<f64>,
+    pub trade_count: DailyValue<u64>,
+    pub low: DailyValue<f64>,
+    pub high: DailyValue<f64>,
+    pub open: f64,
+}
+
+fn decode_value<T: Copy>(
+    values: &[T],
+    field: &'static str,
+    index: usize,
+) -> Result<T, TickerDecodeError> {
+    values
+        .get(index)
+        .copied()
+        .ok_or(TickerDecodeError::Missing { field, index })
+}
+
+fn decode_number(
+    values: &[String],
+    field: &'static str,
+    index: usize,
+) -> Result<f64, TickerDecodeError> {
+    parse_number(
+        values
+            .get(index)
+            .ok_or(TickerDecodeError::Missing { field, index })?,
+        field,
+    )
+}
+
+fn parse_number(value: &str, field: &'static str) -> Result<f64, TickerDecodeError> {
+    value
+        .parse()
+        .map_err(|source| TickerDecodeError::InvalidNumber { field, source })
+}
+
+fn decode_book_top(values: &[String], field: &'static str) -> Result<BookTop, TickerDecodeError> {
+    Ok(BookTop {
+        price: decode_number(values, field, 0)?,
+        whole_lot_volume: decode_number(values, field, 1)?,
+        lot_volume: decode_number(values, field, 2)?,
+    })
+}
+
+fn decode_daily(
+    values: &[String],
+    field: &'static str,
+) -> Result<DailyValue<f64>, TickerDecodeError> {
+    Ok(DailyValue {
+        today: decode_number(values, field, 0)?,
+        last_24_hours: decode_number(values, field, 1)?,
+    })
+}
+
+impl AssetTicker {
+    /// Decodes Kraken's compact positional arrays into named numeric fields.
+    pub fn decode(&self) -> Result<DecodedTicker, TickerDecodeError> {
+        Ok(DecodedTicker {
+            ask: decode_book_top(&self.ask, "ask")?,
+            bid: decode_book_top(&self.bid, "bid")?,
+            last_trade: LastTrade {
+                price: decode_number(&self.last_trade_closed, "last_trade_closed", 0)?,
+                lot_volume: decode_number(&self.last_trade_closed, "last_trade_closed", 1)?,
+            },
+            volume: decode_daily(&self.volume, "volume")?,
+            vwap: decode_daily(
+                &self.volume_weighted_average_price,
+                "volume_weighted_average_price",
+            )?,
+            trade_count: DailyValue {
+                today: decode_value(&self.num_trades, "num_trades", 0)?,
+                last_24_hours: decode_value(&self.num_trades, "num_trades", 1)?,
+            },
+            low: decode_daily(&self.low, "low")?,
+            high: decode_daily(&self.high, "high")?,
+            open: parse_number(&self.opening_price, "opening_price")?,
+        })
+    }
+}
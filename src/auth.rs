@@ -1,6 +1,6 @@
 use base64::{prelude::BASE64_STANDARD, Engine};
 use hmac::Hmac;
-use http::{HeaderMap, HeaderValue};
+use http::{header, HeaderMap, HeaderValue};
 use serde_json::{Map, Value};
 use sha2::{Digest, Sha256, Sha512};
 use std::{
@@ -50,8 +50,23 @@ impl Auth {
     ) {
         let nonce = self.generate_nonce();
 
+        self.sign_spot(headers, path, body, nonce);
+    }
+
+    fn sign_spot(
+        &self,
+        headers: &mut HeaderMap<HeaderValue>,
+        path: &str,
+        body: &mut Map<String, Value>,
+        nonce: u64,
+    ) {
         body.insert("nonce".to_string(), Value::Number(nonce.into()));
-        let encoded_body = serde_urlencoded::to_string(&body).unwrap();
+
+        // The signed payload has to match the body that is sent, which depends on its encoding.
+        let encoded_body = match headers.get(header::CONTENT_TYPE).map(|h| h.to_str()) {
+            Some(Ok("application/json")) => serde_json::to_string(&body).unwrap(),
+            _ => serde_urlencoded::to_string(&body).unwrap(),
+        };
 
         let mut sha256 = Sha256::new();
         Sha256::update(&mut sha256, nonce.to_string().as_bytes());
@@ -86,11 +101,7 @@ impl Auth {
             encoded_body += &parameters.to_string()
         }
 
-        let real_path = if path.starts_with("/derivatives") {
-            &path[12..]
-        } else {
-            &path[..]
-        };
+        let real_path = path.strip_prefix("/derivatives").unwrap_or(path);
 
         let mut sha256 = Sha256::new();
         if !body.is_empty() || params.is_some() {
@@ -122,50 +133,73 @@ impl Auth {
 
         let start = SystemTime::now();
         let since_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let timestamp_nonce = (since_epoch.as_millis() as u64) << 20 | nonce;
-
-        timestamp_nonce
+        (since_epoch.as_millis() as u64) << 20 | nonce
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use base64::{prelude::BASE64_STANDARD, Engine};
-//     use hmac::Hmac;
-//     use http::{HeaderMap, HeaderValue};
-//     use serde_json::{Map, Number, Value};
-//     use sha2::{Digest, Sha256, Sha512};
-//     use std::time::{SystemTime, UNIX_EPOCH};
-
-//     use crate::auth::Auth;
-
-//     #[test]
-//     fn nonce() {
-//         let auth = Auth {
-//             api_key: "".to_owned(),
-//             private_key: vec![]
-//         };
-
-//         println!("nonce {:?}", auth.generate_nonce());
-//     }
-
-//     #[test]
-//     fn it_works() {
-//         let nonce: u64 = 1616492376594;
-
-//         let encoded_body =
-//             "nonce=1616492376594&ordertype=limit&pair=XBTUSD&price=37500&type=buy&volume=1.25";
-//         let mut sha256 = Sha256::new();
-//         Sha256::update(&mut sha256, nonce.to_string().as_bytes());
-//         Sha256::update(&mut sha256, encoded_body.as_bytes());
-
-//         let key = BASE64_STANDARD.decode("kQH5HW/8p1uGOVjbgWA7FunAmGO8lsSUXNsu3eow76sz84Q18fWxnyRzBHCd3pd5nE9qa99HAZtuZuj6F1huXg==").unwrap();
-//         let mut hmac_sha512 = <Hmac<Sha512> as hmac::Mac>::new_from_slice(&key).unwrap();
-//         hmac::digest::Update::update(&mut hmac_sha512, "/0/private/AddOrder".as_bytes());
-//         hmac::digest::Update::update(&mut hmac_sha512, &sha256.finalize());
-
-//         let signature: String =
-//             BASE64_STANDARD.encode(hmac::Mac::finalize(hmac_sha512).into_bytes());
-//         println!("signature {:?}", signature);
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use http::{header, HeaderMap, HeaderValue};
+    use serde_json::{json, Map, Value};
+
+    use crate::auth::Auth;
+
+    // Key and nonce of the signature example in Kraken's REST documentation.
+    const PRIVATE_KEY: &str =
+        "kQH5HW/8p1uGOVjbgWA7FunAmGO8lsSUXNsu3eow76sz84Q18fWxnyRzBHCd3pd5nE9qa99HAZtuZuj6F1huXg==";
+    const NONCE: u64 = 1616492376594;
+
+    fn signature(content_type: &str, path: &str, body: Value) -> String {
+        let auth = Auth::new("key".to_owned(), PRIVATE_KEY.to_owned());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_str(content_type).unwrap(),
+        );
+
+        let mut body: Map<String, Value> = serde_json::from_value(body).unwrap();
+        auth.sign_spot(&mut headers, path, &mut body, NONCE);
+
+        headers["API-Sign"].to_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn spot_form_signature() {
+        let body = json!({
+            "ordertype": "limit",
+            "pair": "XBTUSD",
+            "price": "37500",
+            "type": "buy",
+            "volume": "1.25",
+        });
+
+        assert_eq!(
+            signature(
+                "application/x-www-form-urlencoded",
+                "/0/private/AddOrder",
+                body
+            ),
+            "4/dpxb3iT4tp/ZCVEwSnEsLxx0bqyhLpdfOpc6fn7OR8+UClSV5n9E6aSS8MPtnRfp32bAb0nmbRn6H8ndwLUQ=="
+        );
+    }
+
+    #[test]
+    fn spot_json_signature() {
+        // Signs `{"nonce":1616492376594,"orders":[...],"pair":"XBTUSD"}`.
+        let body = json!({
+            "orders": [{
+                "ordertype": "limit",
+                "price": "37500",
+                "type": "buy",
+                "volume": "1.25",
+            }],
+            "pair": "XBTUSD",
+        });
+
+        assert_eq!(
+            signature("application/json", "/0/private/AddOrderBatch", body),
+            "rzl+k43rPH0j56mXOUpWBR9S9U9khY5J6CEjoJBkSSihIC5cVy8DynLZLlxUrMNLp8JdnA2DluEsWovgoazBKA=="
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use futures_util::{
+    stream::{SplitSink, SplitStream},
+    SinkExt, StreamExt,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, oneshot, Mutex as AsyncMutex},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use crate::api::error::ApiError;
+
+use super::{
+    error::{WsError, WsResult},
+    messages::ChannelMessage,
+    subscription::Subscription,
+};
+
+pub const WS_PUBLIC_URL: &str = "wss://ws.kraken.com/v2";
+pub const WS_PRIVATE_URL: &str = "wss://ws-auth.kraken.com/v2";
+
+/// How long a request waits for the response carrying its `req_id`.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type Pending = Arc<Mutex<HashMap<u64, PendingRequest>>>;
+
+/// Response to a request, matched to it by `req_id`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct MethodResponse {
+    pub method: String,
+    pub req_id: Option<u64>,
+    /// Absent on `pong`.
+    pub success: Option<bool>,
+    pub error: Option<String>,
+    pub result: Option<Value>,
+    pub time_in: Option<String>,
+    pub time_out: Option<String>,
+}
+
+/// A request waiting for `remaining` more responses with its `req_id`.
+#[derive(Debug)]
+struct PendingRequest {
+    remaining: usize,
+    responses: Vec<MethodResponse>,
+    sender: oneshot::Sender<Vec<MethodResponse>>,
+}
+
+/// Channel messages of the connection, in the order Kraken sent them.
+#[derive(Debug)]
+pub struct WsEvents {
+    receiver: mpsc::UnboundedReceiver<WsResult<ChannelMessage>>,
+}
+
+impl WsEvents {
+    /// Returns `None` once the connection is closed.
+    pub async fn recv(&mut self) -> Option<WsResult<ChannelMessage>> {
+        self.receiver.recv().await
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    sink: AsyncMutex<SplitSink<WsStream, Message>>,
+    pending: Pending,
+    next_req_id: AtomicU64,
+    request_timeout: Duration,
+    token: Option<String>,
+}
+
+/// Async client of the Kraken spot WebSocket v2 API. It is cheap to clone and every clone shares
+/// the same connection.
+#[derive(Debug, Clone)]
+pub struct KrakenWs {
+    inner: Arc<Inner>,
+}
+
+impl KrakenWs {
+    /// Connects to the public endpoint.
+    pub async fn connect() -> WsResult<(Self, WsEvents)> {
+        Self::connect_to(WS_PUBLIC_URL, DEFAULT_REQUEST_TIMEOUT).await
+    }
+
+    pub async fn connect_to(url: &str, request_timeout: Duration) -> WsResult<(Self, WsEvents)> {
+        Self::open(url, None, request_timeout).await
+    }
+
+    /// Connects to the authenticated endpoint with a token from `GetWebSocketsToken`. The token
+    /// stays valid as long as the connection is open once a private channel is subscribed.
+    pub async fn connect_private(token: impl Into<String>) -> WsResult<(Self, WsEvents)> {
+        Self::connect_private_to(WS_PRIVATE_URL, token, DEFAULT_REQUEST_TIMEOUT).await
+    }
+
+    pub async fn connect_private_to(
+        url: &str,
+        token: impl Into<String>,
+        request_timeout: Duration,
+    ) -> WsResult<(Self, WsEvents)> {
+        Self::open(url, Some(token.into()), request_timeout).await
+    }
+
+    async fn open(
+        url: &str,
+        token: Option<String>,
+        request_timeout: Duration,
+    ) -> WsResult<(Self, WsEvents)> {
+        let (stream, _) = connect_async(url).await.map_err(WsError::from)?;
+        let (sink, stream) = stream.split();
+
+        let pending = Pending::default();
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        tokio::spawn(read_loop(stream, pending.clone(), sender));
+
+        let client = Self {
+            inner: Arc::new(Inner {
+                sink: AsyncMutex::new(sink),
+                pending,
+                next_req_id: AtomicU64::new(1),
+                request_timeout,
+                token,
+            }),
+        };
+
+        Ok((client, WsEvents { receiver }))
+    }
+
+    /// Sends `method` with `params` and waits for the response with the same `req_id`.
+    pub async fn request<P>(&self, method: &str, params: Option<P>) -> WsResult<MethodResponse>
+    where
+        P: Serialize,
+    {
+        let mut responses = self.send_request(method, params, 1).await?;
+
+        check_responses(method, &responses)?;
+
+        Ok(responses.remove(0))
+    }
+
+    /// Sends `method` with `params` and waits for `expected` responses with the same `req_id`.
+    async fn send_request<P>(
+        &self,
+        method: &str,
+        params: Option<P>,
+        expected: usize,
+    ) -> WsResult<Vec<MethodResponse>>
+    where
+        P: Serialize,
+    {
+        let req_id = self.inner.next_req_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = oneshot::channel();
+        self.inner.pending.lock().unwrap().insert(
+            req_id,
+            PendingRequest {
+                remaining: expected,
+                responses: Vec::with_capacity(expected),
+                sender,
+            },
+        );
+
+        let mut request = json!({ "method": method, "req_id": req_id });
+        if let Some(params) = params {
+            request["params"] =
+                serde_json::to_value(params).map_err(|source| WsError::Serialization { source })?;
+        }
+
+        if let Err(err) = self.send(Message::Text(request.to_string())).await {
+            self.inner.pending.lock().unwrap().remove(&req_id);
+            return Err(err);
+        }
+
+        match tokio::time::timeout(self.inner.request_timeout, receiver).await {
+            Ok(Ok(responses)) => Ok(responses),
+            Ok(Err(_)) => Err(WsError::Closed.into()),
+            Err(_) => {
+                // A rejection of the whole request is only acknowledged once.
+                let request = self.inner.pending.lock().unwrap().remove(&req_id);
+                if let Some(request) = request {
+                    check_responses(method, &request.responses)?;
+                }
+
+                Err(WsError::Timeout {
+                    method: method.to_string(),
+                }
+                .into())
+            }
+        }
+    }
+
+    /// Application level ping, Kraken answers with a `pong` carrying the same `req_id`.
+    pub async fn ping(&self) -> WsResult<MethodResponse> {
+        self.request::<()>("ping", None).await
+    }
+
+    /// Kraken acknowledges each symbol separately with the same `req_id`, all the
+    /// acknowledgements are returned. `Rejected` lists the symbols Kraken refused, the others of
+    /// the request stay subscribed.
+    pub async fn subscribe(&self, subscription: &Subscription) -> WsResult<Vec<MethodResponse>> {
+        let subscription = self.with_token(subscription.clone())?;
+
+        self.acknowledged("subscribe", subscription).await
+    }
+
+    /// Acknowledged per symbol, see `subscribe`.
+    pub async fn unsubscribe(&self, subscription: &Subscription) -> WsResult<Vec<MethodResponse>> {
+        let subscription = self.with_token(Subscription {
+            snapshot: None,
+            snap_orders: None,
+            snap_trades: None,
+            ..subscription.clone()
+        })?;
+
+        self.acknowledged("unsubscribe", subscription).await
+    }
+
+    async fn acknowledged(
+        &self,
+        method: &str,
+        subscription: Subscription,
+    ) -> WsResult<Vec<MethodResponse>> {
+        let expected = subscription.symbols.as_ref().map_or(1, |symbols| {
+            symbols.iter().collect::<HashSet<_>>().len().max(1)
+        });
+
+        let responses = self
+            .send_request(method, Some(subscription), expected)
+            .await?;
+
+        check_responses(method, &responses)?;
+
+        Ok(responses)
+    }
+
+    /// The token of the connection, required by private channels and trading methods.
+    pub fn token(&self) -> WsResult<&str> {
+        self.inner.token.as_deref().ok_or_else(|| {
+            WsError::InvalidRequest {
+                reason: "the connection was not opened with a token".to_string(),
+            }
+            .into()
+        })
+    }
+
+    fn with_token(&self, mut subscription: Subscription) -> WsResult<Subscription> {
+        if subscription.channel.is_private() && subscription.token.is_none() {
+            subscription.token = Some(self.token()?.to_string());
+        }
+
+        Ok(subscription)
+    }
+
+    pub async fn close(&self) -> WsResult<()> {
+        self.send(Message::Close(None)).await
+    }
+
+    async fn send(&self, message: Message) -> WsResult<()> {
+        let mut sink = self.inner.sink.lock().await;
+        sink.send(message).await.map_err(WsError::from)?;
+
+        Ok(())
+    }
+}
+
+/// Fails with `Rejected` when any of the responses has `success: false`.
+fn check_responses(method: &str, responses: &[MethodResponse]) -> WsResult<()> {
+    let errors: Vec<&str> = responses
+        .iter()
+        .filter(|rsp| rsp.success == Some(false))
+        .map(|rsp| rsp.error.as_deref().unwrap_or_default())
+        .collect();
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(WsError::Rejected {
+        method: method.to_string(),
+        error: errors.join("; "),
+    }
+    .into())
+}
+
+/// Reads the connection until it closes, resolving pending requests and forwarding channel
+/// messages. WebSocket pings are answered by tungstenite while reading.
+async fn read_loop(
+    mut stream: SplitStream<WsStream>,
+    pending: Pending,
+    events: mpsc::UnboundedSender<WsResult<ChannelMessage>>,
+) {
+    while let Some(frame) = stream.next().await {
+        match frame {
+            Ok(Message::Text(text)) => dispatch(&text, &pending, &events),
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(source) => {
+                let _ = events.send(Err(WsError::from(source).into()));
+                break;
+            }
+        }
+    }
+
+    // Dropping the senders fails the requests still waiting with `WsError::Closed`.
+    pending.lock().unwrap().clear();
+}
+
+fn dispatch(
+    text: &str,
+    pending: &Pending,
+    events: &mpsc::UnboundedSender<WsResult<ChannelMessage>>,
+) {
+    let value: Value = match serde_json::from_str(text) {
+        Ok(value) => value,
+        Err(source) => {
+            let _ = events.send(Err(ApiError::DataType {
+                typename: "serde_json::Value",
+                obj: Value::String(text.to_string()),
+                source,
+            }));
+            return;
+        }
+    };
+
+    if value.get("method").is_some() {
+        match serde_json::from_value::<MethodResponse>(value.clone()) {
+            Ok(rsp) => {
+                let Some(req_id) = rsp.req_id else {
+                    return;
+                };
+
+                let mut pending = pending.lock().unwrap();
+                let Some(request) = pending.get_mut(&req_id) else {
+                    return;
+                };
+
+                request.responses.push(rsp);
+                request.remaining = request.remaining.saturating_sub(1);
+
+                if request.remaining == 0 {
+                    if let Some(request) = pending.remove(&req_id) {
+                        let _ = request.sender.send(request.responses);
+                    }
+                }
+            }
+            Err(source) => {
+                let _ = events.send(Err(ApiError::DataType {
+                    typename: "MethodResponse",
+                    obj: value,
+                    source,
+                }));
+            }
+        }
+
+        return;
+    }
+
+    let message = serde_json::from_value::<ChannelMessage>(value.clone()).map_err(|source| {
+        ApiError::DataType {
+            typename: "ChannelMessage",
+            obj: value,
+            source,
+        }
+    });
+
+    let _ = events.send(message);
+}
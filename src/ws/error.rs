@@ -0,0 +1,43 @@
+use thiserror::Error;
+use tokio_tungstenite::tungstenite;
+
+use crate::api::error::ApiError;
+
+#[derive(Debug, Error)]
+pub enum WsError {
+    /// Boxed, the tungstenite error is large.
+    #[error("WebSocket error: {}", source)]
+    WebSocket { source: Box<tungstenite::Error> },
+
+    /// Kraken answered the request with `success: false`.
+    #[error("Kraken rejected `{}`: {}", method, error)]
+    Rejected { method: String, error: String },
+
+    #[error("Invalid request: {}", reason)]
+    InvalidRequest { reason: String },
+
+    #[error("Failed to serialize the request: {}", source)]
+    Serialization { source: serde_json::Error },
+
+    #[error("No response to `{}` in time", method)]
+    Timeout { method: String },
+
+    #[error("The connection is closed")]
+    Closed,
+}
+
+impl From<tungstenite::Error> for WsError {
+    fn from(source: tungstenite::Error) -> Self {
+        WsError::WebSocket {
+            source: Box::new(source),
+        }
+    }
+}
+
+impl From<WsError> for ApiError<WsError> {
+    fn from(source: WsError) -> Self {
+        ApiError::client(source)
+    }
+}
+
+pub type WsResult<T> = Result<T, ApiError<WsError>>;
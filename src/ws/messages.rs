@@ -0,0 +1,150 @@
+use serde::Deserialize;
+
+use crate::api::spot::authenticated::add_order::OrderSide;
+
+use super::private::{Balance, ExecutionReport};
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageType {
+    Snapshot,
+    Update,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ChannelData<T> {
+    #[serde(rename = "type")]
+    pub kind: MessageType,
+    pub data: T,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SystemStatus {
+    pub api_version: String,
+    pub connection_id: Option<u64>,
+    pub system: String,
+    pub version: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Ticker {
+    pub symbol: String,
+    pub bid: f64,
+    pub bid_qty: f64,
+    pub ask: f64,
+    pub ask_qty: f64,
+    pub last: f64,
+    pub volume: f64,
+    pub vwap: f64,
+    pub low: f64,
+    pub high: f64,
+    pub change: f64,
+    pub change_pct: f64,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct BookLevel {
+    pub price: f64,
+    pub qty: f64,
+}
+
+/// A book snapshot, or the changed levels of an update where a `qty` of 0 removes the level.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Book {
+    pub symbol: String,
+    #[serde(default)]
+    pub bids: Vec<BookLevel>,
+    #[serde(default)]
+    pub asks: Vec<BookLevel>,
+    pub checksum: u32,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TradeOrderType {
+    Market,
+    Limit,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Trade {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub price: f64,
+    pub qty: f64,
+    pub ord_type: TradeOrderType,
+    pub trade_id: u64,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Candle {
+    pub symbol: String,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub trades: u64,
+    pub volume: f64,
+    pub vwap: f64,
+    pub interval_begin: String,
+    /// Minutes.
+    pub interval: u32,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct InstrumentAsset {
+    pub id: String,
+    pub status: String,
+    pub precision: u32,
+    pub precision_display: u32,
+    pub borrowable: bool,
+    pub collateral_value: f64,
+    pub margin_rate: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct InstrumentPair {
+    pub symbol: String,
+    pub base: String,
+    pub quote: String,
+    pub status: String,
+    pub qty_precision: u32,
+    pub qty_increment: f64,
+    pub qty_min: f64,
+    pub price_precision: u32,
+    pub price_increment: f64,
+    pub cost_precision: u32,
+    pub cost_min: Option<f64>,
+    pub marginable: bool,
+    pub has_index: bool,
+    pub margin_initial: Option<f64>,
+    pub position_limit_long: Option<u64>,
+    pub position_limit_short: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Instruments {
+    #[serde(default)]
+    pub assets: Vec<InstrumentAsset>,
+    #[serde(default)]
+    pub pairs: Vec<InstrumentPair>,
+}
+
+/// Messages pushed by Kraken on the subscribed channels, `Executions` and `Balances` require an
+/// authenticated connection.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "channel", rename_all = "lowercase")]
+pub enum ChannelMessage {
+    Heartbeat,
+    Status(ChannelData<Vec<SystemStatus>>),
+    Ticker(ChannelData<Vec<Ticker>>),
+    Book(ChannelData<Vec<Book>>),
+    Trade(ChannelData<Vec<Trade>>),
+    Ohlc(ChannelData<Vec<Candle>>),
+    Instrument(ChannelData<Instruments>),
+    Executions(ChannelData<Vec<ExecutionReport>>),
+    Balances(ChannelData<Vec<Balance>>),
+}
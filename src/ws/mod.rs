@@ -0,0 +1,6 @@
+pub mod client;
+pub mod error;
+pub mod messages;
+pub mod private;
+pub mod subscription;
+pub mod trading;
\ No newline at end of file
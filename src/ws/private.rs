@@ -0,0 +1,146 @@
+use serde::Deserialize;
+
+use crate::api::spot::authenticated::add_order::{OrderSide, TimeInForce};
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecType {
+    PendingNew,
+    New,
+    Trade,
+    Filled,
+    IcebergRefill,
+    Canceled,
+    Expired,
+    Amended,
+    Restated,
+    Status,
+    /// A value this crate does not know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    PendingNew,
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    Expired,
+    /// A value this crate does not know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LiquidityIndicator {
+    #[serde(rename = "t")]
+    Taker,
+    #[serde(rename = "m")]
+    Maker,
+    /// A value this crate does not know yet.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExecutionFee {
+    pub asset: String,
+    pub qty: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExecutionTriggers {
+    pub reference: Option<String>,
+    pub price: f64,
+    pub price_type: Option<String>,
+    pub actual_price: Option<f64>,
+    pub peak_price: Option<f64>,
+    pub last_price: Option<f64>,
+    pub status: Option<String>,
+    pub timestamp: Option<String>,
+}
+
+/// Execution report of an order. Updates only carry the fields that changed, trade fields are
+/// set when `exec_type` is `trade` or `filled`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExecutionReport {
+    pub order_id: String,
+    pub exec_type: ExecType,
+    pub order_status: Option<OrderStatus>,
+    pub timestamp: String,
+    pub symbol: Option<String>,
+    pub side: Option<OrderSide>,
+    /// Left as a string so that order types added by Kraken don't fail the whole batch.
+    pub order_type: Option<String>,
+    pub order_userref: Option<i64>,
+    pub cl_ord_id: Option<String>,
+    pub order_qty: Option<f64>,
+    pub display_qty: Option<f64>,
+    pub limit_price: Option<f64>,
+    pub triggers: Option<ExecutionTriggers>,
+    pub time_in_force: Option<TimeInForce>,
+    pub post_only: Option<bool>,
+    pub reduce_only: Option<bool>,
+    pub margin: Option<bool>,
+    pub cum_qty: Option<f64>,
+    pub cum_cost: Option<f64>,
+    pub avg_price: Option<f64>,
+    pub exec_id: Option<String>,
+    pub trade_id: Option<u64>,
+    pub last_qty: Option<f64>,
+    pub last_price: Option<f64>,
+    pub cost: Option<f64>,
+    pub liquidity_ind: Option<LiquidityIndicator>,
+    #[serde(default)]
+    pub fees: Vec<ExecutionFee>,
+    pub fee_usd_equiv: Option<f64>,
+    pub reason: Option<String>,
+    pub amended: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Wallet {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub id: String,
+    pub balance: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AssetBalance {
+    pub asset: String,
+    pub asset_class: Option<String>,
+    pub balance: f64,
+    #[serde(default)]
+    pub wallets: Vec<Wallet>,
+}
+
+/// A ledger entry that changed the balance of an asset.
+#[derive(Debug, Deserialize, Clone)]
+pub struct LedgerUpdate {
+    pub ledger_id: String,
+    pub ref_id: String,
+    pub timestamp: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub subtype: Option<String>,
+    pub asset: String,
+    pub asset_class: Option<String>,
+    pub category: Option<String>,
+    pub wallet_type: Option<String>,
+    pub wallet_id: Option<String>,
+    pub amount: f64,
+    pub fee: f64,
+    pub balance: f64,
+}
+
+/// Snapshots carry the balance of every asset, updates the ledger entries since.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Balance {
+    Update(LedgerUpdate),
+    Snapshot(AssetBalance),
+}
@@ -0,0 +1,162 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::api::common::{Symbols, TimeFrame};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    Ticker,
+    Book,
+    Trade,
+    Ohlc,
+    Instrument,
+    Executions,
+    Balances,
+}
+
+impl Channel {
+    fn requires_symbols(&self) -> bool {
+        !matches!(
+            self,
+            Channel::Instrument | Channel::Executions | Channel::Balances
+        )
+    }
+
+    /// Private channels are only available on the authenticated endpoint and need a token.
+    pub fn is_private(&self) -> bool {
+        matches!(self, Channel::Executions | Channel::Balances)
+    }
+}
+
+/// Candle intervals supported by the `ohlc` channel, sent in minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OhlcInterval {
+    OneMin,
+    FiveMins,
+    FifteenMins,
+    ThirtyMins,
+    OneHour,
+    FourHours,
+    OneDay,
+    OneWeek,
+    FifteenDays,
+}
+
+impl OhlcInterval {
+    pub fn minutes(&self) -> u32 {
+        match self {
+            OhlcInterval::OneMin => 1,
+            OhlcInterval::FiveMins => 5,
+            OhlcInterval::FifteenMins => 15,
+            OhlcInterval::ThirtyMins => 30,
+            OhlcInterval::OneHour => 60,
+            OhlcInterval::FourHours => 240,
+            OhlcInterval::OneDay => 1440,
+            OhlcInterval::OneWeek => 10080,
+            OhlcInterval::FifteenDays => 21600,
+        }
+    }
+}
+
+impl Serialize for OhlcInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(self.minutes())
+    }
+}
+
+impl TryFrom<TimeFrame> for OhlcInterval {
+    type Error = String;
+
+    fn try_from(time_frame: TimeFrame) -> Result<Self, Self::Error> {
+        match time_frame {
+            TimeFrame::OneMin => Ok(OhlcInterval::OneMin),
+            TimeFrame::FiveMins => Ok(OhlcInterval::FiveMins),
+            TimeFrame::FifteenMins => Ok(OhlcInterval::FifteenMins),
+            TimeFrame::ThirtyMins => Ok(OhlcInterval::ThirtyMins),
+            TimeFrame::OneHour => Ok(OhlcInterval::OneHour),
+            TimeFrame::OneDay => Ok(OhlcInterval::OneDay),
+            TimeFrame::OneWeek => Ok(OhlcInterval::OneWeek),
+            _ => Err(format!("Interval {time_frame} is not supported by ohlc")),
+        }
+    }
+}
+
+/// Parameters of a `subscribe` or `unsubscribe` request.
+#[derive(Debug, Clone, Builder, Serialize)]
+#[builder(build_fn(validate = "Self::validate"), setter(strip_option, into))]
+pub struct Subscription {
+    pub channel: Channel,
+    #[builder(setter(custom), default)]
+    #[serde(rename = "symbol", skip_serializing_if = "Option::is_none")]
+    pub symbols: Option<Vec<String>>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<u32>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<OhlcInterval>,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<bool>,
+    /// Snapshot of the open orders on `executions`.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snap_orders: Option<bool>,
+    /// Snapshot of the last 50 trades on `executions`.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snap_trades: Option<bool>,
+    /// Filled in by `KrakenWs::subscribe` for private channels when not set.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl SubscriptionBuilder {
+    pub fn symbols(&mut self, symbols: Symbols<'_>) -> &mut Self {
+        self.symbols = Some(match symbols {
+            Symbols::All => None,
+            Symbols::Only(symbols) => Some(symbols.into_iter().map(str::to_owned).collect()),
+        });
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let Some(channel) = self.channel else {
+            return Ok(());
+        };
+
+        if channel.requires_symbols()
+            && self
+                .symbols
+                .clone()
+                .flatten()
+                .map_or(0, |symbols| symbols.len())
+                == 0
+        {
+            return Err(format!("Channel {channel:?} requires at least one symbol"));
+        }
+
+        if channel == Channel::Ohlc && self.interval.flatten().is_none() {
+            return Err("Field interval is required for ohlc".to_string());
+        }
+
+        if let Some(Some(depth)) = self.depth {
+            if ![10, 25, 100, 500, 1000].contains(&depth) {
+                return Err("Field depth must be one of 10, 25, 100, 500 or 1000".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Subscription {
+    pub fn builder() -> SubscriptionBuilder {
+        SubscriptionBuilder::default()
+    }
+}
@@ -0,0 +1,609 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::api::{
+    error::ApiError,
+    spot::authenticated::{
+        add_order::{AddOrder, OrderType, SelfTradePrevention, TimeInForce},
+        add_order_batch::AddOrderBatch,
+        amend_order::AmendOrder,
+        cancel_order::CancelOrder,
+        cancel_order_batch::CancelOrderBatch,
+        edit_order::EditOrder,
+    },
+};
+
+use super::{
+    client::{KrakenWs, MethodResponse},
+    error::{WsError, WsResult},
+};
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AddOrderResult {
+    pub order_id: String,
+    pub cl_ord_id: Option<String>,
+    pub order_userref: Option<i64>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmendOrderResult {
+    pub amend_id: String,
+    pub order_id: Option<String>,
+    pub cl_ord_id: Option<String>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EditOrderResult {
+    pub order_id: String,
+    pub original_order_id: String,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelOrderResult {
+    pub order_id: Option<String>,
+    pub cl_ord_id: Option<String>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelCountResult {
+    pub count: u32,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOnDisconnectResult {
+    pub current_time: String,
+    pub trigger_time: String,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+fn to_wire<T: Serialize>(value: &T) -> WsResult<Value> {
+    serde_json::to_value(value).map_err(|source| WsError::Serialization { source }.into())
+}
+
+fn insert_opt<T: Serialize>(
+    params: &mut Map<String, Value>,
+    key: &str,
+    value: Option<T>,
+) -> WsResult<()> {
+    if let Some(value) = value {
+        params.insert(key.to_string(), to_wire(&value)?);
+    }
+
+    Ok(())
+}
+
+fn time_in_force(time_in_force: &TimeInForce) -> &'static str {
+    match time_in_force {
+        TimeInForce::Gtc => "gtc",
+        TimeInForce::Ioc => "ioc",
+        TimeInForce::Gtd => "gtd",
+    }
+}
+
+fn stp_type(stp: SelfTradePrevention) -> &'static str {
+    match stp {
+        SelfTradePrevention::CancelNewest => "cancel_newest",
+        SelfTradePrevention::CancelOldest => "cancel_oldest",
+        SelfTradePrevention::CancelBoth => "cancel_both",
+    }
+}
+
+fn is_triggered(order_type: &OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::StopLoss
+            | OrderType::TakeProfit
+            | OrderType::TrailingStop
+            | OrderType::StopLossLimit
+            | OrderType::TakeProfitLimit
+            | OrderType::TrailingStopLimit
+    )
+}
+
+fn is_triggered_limit(order_type: &OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::StopLossLimit | OrderType::TakeProfitLimit | OrderType::TrailingStopLimit
+    )
+}
+
+fn is_trailing(order_type: &OrderType) -> bool {
+    matches!(
+        order_type,
+        OrderType::TrailingStop | OrderType::TrailingStopLimit
+    )
+}
+
+/// REST trailing stops take `+` offsets from the market price and `AddOrder` has no `%` form,
+/// so their prices are offsets in the quote currency. The other prices are static.
+fn price_type(order_type: &OrderType) -> &'static str {
+    if is_trailing(order_type) {
+        "quote"
+    } else {
+        "static"
+    }
+}
+
+/// WebSocket orders can only be funded on margin at the maximum leverage of the pair, which no
+/// REST `leverage` value guarantees, so only unleveraged values are accepted.
+fn check_leverage(leverage: Option<&str>) -> WsResult<()> {
+    match leverage {
+        None | Some("none" | "1" | "1:1") => Ok(()),
+        Some(leverage) => Err(WsError::InvalidRequest {
+            reason: format!("leverage {leverage} cannot be represented over WebSocket"),
+        }
+        .into()),
+    }
+}
+
+/// The REST order flags are separate fields over WebSocket.
+fn insert_oflags(
+    params: &mut Map<String, Value>,
+    oflags: Option<&str>,
+    volume: Option<f64>,
+) -> WsResult<()> {
+    for flag in oflags.unwrap_or_default().split(',') {
+        match flag.trim() {
+            "post" => insert_opt(params, "post_only", Some(true))?,
+            "fcib" => insert_opt(params, "fee_preference", Some("base"))?,
+            "fciq" => insert_opt(params, "fee_preference", Some("quote"))?,
+            "nompp" => insert_opt(params, "no_mpp", Some(true))?,
+            "viqc" => {
+                params.remove("order_qty");
+                insert_opt(params, "cash_order_qty", volume)?;
+            }
+            "" => {}
+            flag => {
+                return Err(WsError::InvalidRequest {
+                    reason: format!("order flag {flag} is not supported over WebSocket"),
+                }
+                .into())
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts a REST order to the WebSocket order parameters, without `symbol` which `batch_add`
+/// sets once for all the orders. `price` is the trigger price of stop and take profit orders
+/// and `price2` their limit price, as in REST. `starttm` and `expiretm` have to be RFC3339
+/// timestamps.
+fn order_params(order: &AddOrder) -> WsResult<Map<String, Value>> {
+    check_leverage(order.leverage.as_deref())?;
+
+    let mut params = Map::new();
+    params.insert("order_type".to_string(), to_wire(&order.order_type)?);
+    params.insert("side".to_string(), to_wire(&order.side)?);
+    params.insert("order_qty".to_string(), json!(order.volume));
+
+    if is_triggered(&order.order_type) {
+        let mut triggers = Map::new();
+        insert_opt(&mut triggers, "price", order.price)?;
+        insert_opt(
+            &mut triggers,
+            "price_type",
+            Some(price_type(&order.order_type)),
+        )?;
+        insert_opt(&mut triggers, "reference", order.trigger.as_ref())?;
+        params.insert("triggers".to_string(), Value::Object(triggers));
+
+        if is_triggered_limit(&order.order_type) {
+            insert_opt(&mut params, "limit_price", order.price2)?;
+
+            if is_trailing(&order.order_type) {
+                insert_opt(&mut params, "limit_price_type", Some("quote"))?;
+            }
+        }
+    } else {
+        insert_opt(&mut params, "limit_price", order.price)?;
+    }
+
+    insert_opt(&mut params, "display_qty", order.display_vol)?;
+    insert_opt(
+        &mut params,
+        "time_in_force",
+        order.time_in_force.as_ref().map(time_in_force),
+    )?;
+    insert_opt(&mut params, "reduce_only", order.reduce_only)?;
+    insert_opt(&mut params, "stp_type", order.stptype.map(stp_type))?;
+    insert_opt(&mut params, "effective_time", order.starttm.as_ref())?;
+    insert_opt(&mut params, "expire_time", order.expiretm.as_ref())?;
+    insert_opt(&mut params, "cl_ord_id", order.cl_ord_id.as_ref())?;
+    insert_opt(&mut params, "order_userref", order.userref)?;
+
+    if let Some(close_order_type) = &order.close_order_type {
+        let mut conditional = Map::new();
+        conditional.insert("order_type".to_string(), to_wire(close_order_type)?);
+
+        if is_triggered(close_order_type) {
+            insert_opt(&mut conditional, "trigger_price", order.close_price)?;
+            insert_opt(
+                &mut conditional,
+                "trigger_price_type",
+                Some(price_type(close_order_type)),
+            )?;
+
+            if is_triggered_limit(close_order_type) {
+                insert_opt(&mut conditional, "limit_price", order.close_price2)?;
+            }
+        } else {
+            insert_opt(&mut conditional, "limit_price", order.close_price)?;
+        }
+
+        params.insert("conditional".to_string(), Value::Object(conditional));
+    }
+
+    insert_oflags(&mut params, order.oflags.as_deref(), Some(order.volume))?;
+
+    Ok(params)
+}
+
+fn parse_result<T: DeserializeOwned>(rsp: MethodResponse, typename: &'static str) -> WsResult<T> {
+    let obj = rsp.result.unwrap_or(Value::Null);
+
+    serde_json::from_value(obj.clone()).map_err(|source| ApiError::DataType {
+        typename,
+        obj,
+        source,
+    })
+}
+
+/// Trading methods, they need a connection opened with `KrakenWs::connect_private`.
+impl KrakenWs {
+    fn token_value(&self) -> WsResult<Value> {
+        Ok(json!(self.token()?))
+    }
+
+    pub async fn add_order(&self, order: &AddOrder) -> WsResult<AddOrderResult> {
+        let mut params = order_params(order)?;
+        params.insert("symbol".to_string(), json!(order.pair));
+        insert_opt(&mut params, "deadline", order.deadline.as_ref())?;
+        insert_opt(&mut params, "validate", order.validate_args)?;
+        params.insert("token".to_string(), self.token_value()?);
+
+        let rsp = self.request("add_order", Some(params)).await?;
+        parse_result(rsp, "AddOrderResult")
+    }
+
+    pub async fn amend_order(&self, amend: &AmendOrder) -> WsResult<AmendOrderResult> {
+        let mut params = Map::new();
+        insert_opt(&mut params, "order_id", amend.txid.as_ref())?;
+        insert_opt(&mut params, "cl_ord_id", amend.cl_ord_id.as_ref())?;
+        insert_opt(&mut params, "order_qty", amend.order_qty)?;
+        insert_opt(&mut params, "display_qty", amend.display_qty)?;
+        insert_opt(&mut params, "limit_price", amend.limit_price)?;
+        insert_opt(&mut params, "trigger_price", amend.trigger_price)?;
+        insert_opt(&mut params, "post_only", amend.post_only)?;
+        insert_opt(&mut params, "deadline", amend.deadline.as_ref())?;
+        params.insert("token".to_string(), self.token_value()?);
+
+        let rsp = self.request("amend_order", Some(params)).await?;
+        parse_result(rsp, "AmendOrderResult")
+    }
+
+    /// As in REST, `price` is the trigger price when `price2` is set and `price2` the limit
+    /// price, otherwise `price` is the limit price.
+    pub async fn edit_order(&self, edit: &EditOrder) -> WsResult<EditOrderResult> {
+        if edit.cancel_response == Some(true) {
+            return Err(WsError::InvalidRequest {
+                reason: "cancel_response is not supported over WebSocket".to_string(),
+            }
+            .into());
+        }
+
+        let mut params = Map::new();
+        params.insert("order_id".to_string(), json!(edit.txid));
+        params.insert("symbol".to_string(), json!(edit.pair));
+        insert_opt(&mut params, "order_userref", edit.userref)?;
+        insert_opt(&mut params, "order_qty", edit.volume)?;
+        insert_opt(&mut params, "display_qty", edit.display_vol)?;
+
+        match edit.price2 {
+            Some(price2) => {
+                insert_opt(&mut params, "trigger_price", edit.price)?;
+                insert_opt(&mut params, "limit_price", Some(price2))?;
+            }
+            None => insert_opt(&mut params, "limit_price", edit.price)?,
+        }
+
+        insert_opt(&mut params, "deadline", edit.deadline.as_ref())?;
+        insert_opt(&mut params, "validate", edit.validate_args)?;
+        insert_oflags(&mut params, edit.oflags.as_deref(), edit.volume)?;
+        params.insert("token".to_string(), self.token_value()?);
+
+        let rsp = self.request("edit_order", Some(params)).await?;
+        parse_result(rsp, "EditOrderResult")
+    }
+
+    pub async fn cancel_order(&self, cancel: &CancelOrder) -> WsResult<CancelOrderResult> {
+        let mut params = Map::new();
+        insert_opt(
+            &mut params,
+            "order_id",
+            cancel.txid.as_ref().map(|txid| [txid]),
+        )?;
+        insert_opt(
+            &mut params,
+            "order_userref",
+            cancel.userref.map(|userref| [userref]),
+        )?;
+        insert_opt(
+            &mut params,
+            "cl_ord_id",
+            cancel.cl_ord_id.as_ref().map(|cl_ord_id| [cl_ord_id]),
+        )?;
+        params.insert("token".to_string(), self.token_value()?);
+
+        let rsp = self.request("cancel_order", Some(params)).await?;
+        parse_result(rsp, "CancelOrderResult")
+    }
+
+    pub async fn cancel_all(&self) -> WsResult<CancelCountResult> {
+        let params = json!({ "token": self.token_value()? });
+
+        let rsp = self.request("cancel_all", Some(params)).await?;
+        parse_result(rsp, "CancelCountResult")
+    }
+
+    /// Dead man's switch, cancels all the orders when not called again within `timeout`
+    /// seconds. A `timeout` of 0 disables it.
+    pub async fn cancel_on_disconnect(&self, timeout: u64) -> WsResult<CancelOnDisconnectResult> {
+        let params = json!({ "timeout": timeout, "token": self.token_value()? });
+
+        let rsp = self
+            .request("cancel_all_orders_after", Some(params))
+            .await?;
+        parse_result(rsp, "CancelOnDisconnectResult")
+    }
+
+    pub async fn batch_add(&self, batch: &AddOrderBatch) -> WsResult<Vec<AddOrderResult>> {
+        let orders = batch
+            .orders
+            .iter()
+            .map(|order| order_params(order).map(Value::Object))
+            .collect::<WsResult<Vec<_>>>()?;
+
+        let mut params = Map::new();
+        params.insert("orders".to_string(), Value::Array(orders));
+        params.insert("symbol".to_string(), json!(batch.pair));
+        insert_opt(&mut params, "deadline", batch.deadline.as_ref())?;
+        insert_opt(&mut params, "validate", batch.validate_args)?;
+        params.insert("token".to_string(), self.token_value()?);
+
+        let rsp = self.request("batch_add", Some(params)).await?;
+        parse_result(rsp, "Vec<AddOrderResult>")
+    }
+
+    pub async fn batch_cancel(&self, batch: &CancelOrderBatch) -> WsResult<CancelCountResult> {
+        let orders: Vec<String> = batch
+            .txids
+            .iter()
+            .cloned()
+            .chain(batch.userrefs.iter().map(|userref| userref.to_string()))
+            .collect();
+
+        let mut params = Map::new();
+        params.insert("orders".to_string(), json!(orders));
+        if !batch.cl_ord_ids.is_empty() {
+            params.insert("cl_ord_id".to_string(), json!(batch.cl_ord_ids));
+        }
+        params.insert("token".to_string(), self.token_value()?);
+
+        let rsp = self.request("batch_cancel", Some(params)).await?;
+        parse_result(rsp, "CancelCountResult")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::api::spot::authenticated::add_order::{
+        AddOrder, OrderSide, OrderType, TimeInForce, Trigger,
+    };
+
+    use super::order_params;
+
+    fn params(order: &AddOrder) -> Value {
+        Value::Object(order_params(order).unwrap())
+    }
+
+    #[test]
+    fn market_order() {
+        let order = AddOrder::builder()
+            .order_type(OrderType::Market)
+            .side(OrderSide::Buy)
+            .volume(1.25)
+            .pair("BTC/USD")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            params(&order),
+            json!({
+                "order_type": "market",
+                "side": "buy",
+                "order_qty": 1.25,
+            })
+        );
+    }
+
+    #[test]
+    fn limit_order() {
+        let order = AddOrder::builder()
+            .order_type(OrderType::Limit)
+            .side(OrderSide::Sell)
+            .volume(0.5)
+            .price(37500.0)
+            .pair("BTC/USD")
+            .time_in_force(TimeInForce::Gtc)
+            .oflags("post,fciq")
+            .cl_ord_id("my-order")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            params(&order),
+            json!({
+                "order_type": "limit",
+                "side": "sell",
+                "order_qty": 0.5,
+                "limit_price": 37500.0,
+                "time_in_force": "gtc",
+                "cl_ord_id": "my-order",
+                "post_only": true,
+                "fee_preference": "quote",
+            })
+        );
+    }
+
+    #[test]
+    fn stop_loss_limit_order() {
+        let order = AddOrder::builder()
+            .order_type(OrderType::StopLossLimit)
+            .side(OrderSide::Sell)
+            .volume(1.0)
+            .price(36000.0)
+            .price2(35900.0)
+            .trigger(Trigger::Last)
+            .pair("BTC/USD")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            params(&order),
+            json!({
+                "order_type": "stop-loss-limit",
+                "side": "sell",
+                "order_qty": 1.0,
+                "triggers": {
+                    "price": 36000.0,
+                    "price_type": "static",
+                    "reference": "last",
+                },
+                "limit_price": 35900.0,
+            })
+        );
+    }
+
+    #[test]
+    fn trailing_stop_limit_order() {
+        let order = AddOrder::builder()
+            .order_type(OrderType::TrailingStopLimit)
+            .side(OrderSide::Sell)
+            .volume(1.0)
+            .price(500.0)
+            .price2(50.0)
+            .pair("BTC/USD")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            params(&order),
+            json!({
+                "order_type": "trailing-stop-limit",
+                "side": "sell",
+                "order_qty": 1.0,
+                "triggers": {
+                    "price": 500.0,
+                    "price_type": "quote",
+                },
+                "limit_price": 50.0,
+                "limit_price_type": "quote",
+            })
+        );
+    }
+
+    #[test]
+    fn close_order() {
+        let order = AddOrder::builder()
+            .order_type(OrderType::Limit)
+            .side(OrderSide::Buy)
+            .volume(1.0)
+            .price(37500.0)
+            .close_order_type(OrderType::StopLossLimit)
+            .close_price(36000.0)
+            .close_price2(35900.0)
+            .pair("BTC/USD")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            params(&order),
+            json!({
+                "order_type": "limit",
+                "side": "buy",
+                "order_qty": 1.0,
+                "limit_price": 37500.0,
+                "conditional": {
+                    "order_type": "stop-loss-limit",
+                    "trigger_price": 36000.0,
+                    "trigger_price_type": "static",
+                    "limit_price": 35900.0,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn viqc_order() {
+        let order = AddOrder::builder()
+            .order_type(OrderType::Market)
+            .side(OrderSide::Buy)
+            .volume(100.0)
+            .oflags("viqc")
+            .pair("BTC/USD")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            params(&order),
+            json!({
+                "order_type": "market",
+                "side": "buy",
+                "cash_order_qty": 100.0,
+            })
+        );
+    }
+
+    #[test]
+    fn unsupported_leverage() {
+        let order = AddOrder::builder()
+            .order_type(OrderType::Market)
+            .side(OrderSide::Buy)
+            .volume(1.0)
+            .leverage("2:1")
+            .pair("BTC/USD")
+            .build()
+            .unwrap();
+
+        assert!(order_params(&order).is_err());
+    }
+
+    #[test]
+    fn unknown_oflag() {
+        let order = AddOrder::builder()
+            .order_type(OrderType::Market)
+            .side(OrderSide::Buy)
+            .volume(1.0)
+            .oflags("post,unknown")
+            .pair("BTC/USD")
+            .build()
+            .unwrap();
+
+        assert!(order_params(&order).is_err());
+    }
+}